@@ -1,7 +1,10 @@
 use std::iter::once;
 
+use crate::{primitives, value::system};
+
 use super::EvalTree;
 
+#[allow(dead_code)]
 enum BindingType {
     A,
     F,
@@ -23,6 +26,7 @@ impl BindingType {
         match t {
             Array(_) => A,
             Function(_) => F,
+            Isolated(_) => A,
             MonadicFunctionApplication(_, _) => A,
             DyadicFunctionApplication(_, _, _) => A,
             BoundLeftArgument(_, _) => Af,
@@ -31,10 +35,20 @@ impl BindingType {
             DyadicOperatorApplication(_, _, _) => F,
             Atop(_, _) => F,
             Fork(_, _, _) => F,
-            Integer(_) => A,
-            Float(_) => A,
+            Assignment(_, _) => A,
+            Number(_) => A,
+            Character(_) => A,
+            SystemName(name) if system::is_function(name) => F,
+            SystemName(_) => A,
+            Empty(_) => A,
+            LeftArrow => panic!("SYNTAX ERROR"),
             PrimitiveFunction(_) => F,
+            PrimitiveMonadicOperator(
+                primitives::PrimitiveMonadicOperator::Slash
+                | primitives::PrimitiveMonadicOperator::Backslash,
+            ) => H,
             PrimitiveMonadicOperator(_) => Mop,
+            PrimitiveDyadicOperator(primitives::PrimitiveDyadicOperator::Jot) => Jot,
             PrimitiveDyadicOperator(_) => Dop,
             Unfinished(_) => todo!(),
         }
@@ -45,9 +59,9 @@ fn combine_arrays(x: EvalTree, y: EvalTree) -> EvalTree {
     use EvalTree::*;
 
     match (x.clone(), y.clone()) {
-        (Array(x), Array(y)) => Array(x.into_iter().chain(y.into_iter()).collect()),
+        (Array(x), Array(y)) => Array(x.into_iter().chain(y).collect()),
         (Array(x), _) => Array(x.into_iter().chain(once(y)).collect()),
-        (_, Array(y)) => Array(once(x).chain(y.into_iter()).collect()),
+        (_, Array(y)) => Array(once(x).chain(y).collect()),
         (_, _) => Array(vec![x, y]),
     }
 }
@@ -71,19 +85,18 @@ pub fn combine(x: EvalTree, y: EvalTree) -> EvalTree {
     match (bx, by) {
         (A, A) => combine_arrays(x, y),
         (A, F) | (A, H) => BoundLeftArgument(Box::new(x), Box::new(y)),
-        (A, Mop) | (F, Mop) | (H, Mop) | (Jot, Mop) => {
-            MonadicOperatorApplication(Box::new(x), Box::new(y))
-        }
+        (A, Mop) | (F, Mop) | (H, Mop) | (Jot, Mop) | (F, H) | (H, H) => match y {
+            BoundRightOperand(o, r) => DyadicOperatorApplication(Box::new(x), o, r),
+            y => MonadicOperatorApplication(Box::new(x), Box::new(y)),
+        },
         (A, Dot) => todo!(),
         (A, Idx) => todo!(),
         (F, A) => MonadicFunctionApplication(Box::new(x), Box::new(y)),
         (F, F) => combine_functions(x, y),
-        (F, H) => combine_functions(x, y),
         (F, Dot) => todo!(),
         (F, Idx) => todo!(),
-        (H, F) => todo!(),
-        (H, H) => todo!(),
-        (H, Af) => todo!(),
+        (H, F) => combine_functions(x, y),
+        (H, Af) => panic!("SYNTAX ERROR"),
         (Af, A) => {
             if let BoundLeftArgument(l, f) = x {
                 DyadicFunctionApplication(l, f, Box::new(y))
@@ -91,7 +104,13 @@ pub fn combine(x: EvalTree, y: EvalTree) -> EvalTree {
                 unreachable!()
             }
         }
-        (Af, F) => todo!(),
+        (Af, F) => {
+            if let BoundLeftArgument(l, f) = x {
+                Fork(l, f, Box::new(y))
+            } else {
+                unreachable!()
+            }
+        }
         (Dop, A) | (Dop, F) | (Dop, H) | (Jot, A) | (Jot, F) | (Jot, H) | (Dot, F) | (Dot, H) => {
             BoundRightOperand(Box::new(x), Box::new(y))
         }
@@ -126,7 +145,8 @@ fn binding_strength(x: &EvalTree, y: &EvalTree) -> u8 {
         (BindingType::F, BindingType::Idx) => 4,
         (BindingType::H, BindingType::F) => 1,
         (BindingType::H, BindingType::H) => 4,
-        (BindingType::H, BindingType::Mop) => 4,
+        // Below `(F, H)`, so that `+/⍨` is `(+/)⍨` rather than `+ (/⍨)`.
+        (BindingType::H, BindingType::Mop) => 3,
         (BindingType::H, BindingType::Idx) => 4,
         (BindingType::Af, BindingType::A) => 2,
         (BindingType::Af, BindingType::F) => 1,
@@ -153,3 +173,29 @@ fn binding_strength(x: &EvalTree, y: &EvalTree) -> u8 {
         _ => 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval_tree::build_tree,
+        parser::parse,
+        token::tokenize,
+        value::{eval, Value},
+    };
+
+    /// Evaluates a line, giving a function as its tree so that the way it was bound shows.
+    fn run(line: &str) -> String {
+        let tree = build_tree(parse(&tokenize(line).unwrap()));
+        match eval(&tree) {
+            Value::Function(f) => f.tree(),
+            result => result.to_string(),
+        }
+    }
+
+    #[test]
+    fn reduction_binds_before_taking_an_operator() {
+        assert_eq!(run("+/⍤1⊢2 3⍴⍳6"), run("(+/)⍤1⊢2 3⍴⍳6"));
+        assert_eq!(run("+/⍤1⊢2 3⍴⍳6"), "6 15");
+        assert_eq!(run("+/⍨"), run("(+/)⍨"));
+    }
+}
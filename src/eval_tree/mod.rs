@@ -11,6 +11,8 @@ mod binding;
 pub enum EvalTree {
     Array(Vec<EvalTree>),
     Function(Box<EvalTree>),
+    /// A strand in parentheses, which is a single item of any strand around it.
+    Isolated(Box<EvalTree>),
     MonadicFunctionApplication(Box<EvalTree>, Box<EvalTree>),
     DyadicFunctionApplication(Box<EvalTree>, Box<EvalTree>, Box<EvalTree>),
     BoundLeftArgument(Box<EvalTree>, Box<EvalTree>),
@@ -19,9 +21,15 @@ pub enum EvalTree {
     DyadicOperatorApplication(Box<EvalTree>, Box<EvalTree>, Box<EvalTree>),
     Atop(Box<EvalTree>, Box<EvalTree>),
     Fork(Box<EvalTree>, Box<EvalTree>, Box<EvalTree>),
+    /// Assigns the value on the right to the name on the left.
+    Assignment(Box<EvalTree>, Box<EvalTree>),
 
-    Integer(i64),
-    Float(f64),
+    Number(String),
+    Character(char),
+    SystemName(String),
+    /// An empty vector, holding the literal whose value is its prototype.
+    Empty(Box<EvalTree>),
+    LeftArrow,
     PrimitiveFunction(PrimitiveFunction),
     PrimitiveMonadicOperator(PrimitiveMonadicOperator),
     PrimitiveDyadicOperator(PrimitiveDyadicOperator),
@@ -32,8 +40,22 @@ pub enum EvalTree {
 impl EvalTree {
     fn from(expr: Expr) -> EvalTree {
         match expr {
-            Expr::Integer(n) => EvalTree::Integer(n),
-            Expr::Float(n) => EvalTree::Float(n),
+            Expr::Number(n) => EvalTree::Number(n),
+            Expr::String(s) => {
+                let mut chars: Vec<EvalTree> = s.chars().map(EvalTree::Character).collect();
+
+                // A single character is a scalar, and any other string is a vector.
+                if chars.is_empty() {
+                    EvalTree::Empty(Box::new(EvalTree::Character(' ')))
+                } else if chars.len() == 1 {
+                    chars.remove(0)
+                } else {
+                    EvalTree::Isolated(Box::new(EvalTree::Array(chars)))
+                }
+            }
+            Expr::SystemName(name) => EvalTree::SystemName(name),
+            Expr::Zilde => EvalTree::Empty(Box::new(EvalTree::Number("0".to_string()))),
+            Expr::LeftArrow => EvalTree::LeftArrow,
             Expr::PrimitiveFunction(f) => EvalTree::PrimitiveFunction(f),
             Expr::PrimitiveMonadicOperator(o) => EvalTree::PrimitiveMonadicOperator(o),
             Expr::PrimitiveDyadicOperator(o) => EvalTree::PrimitiveDyadicOperator(o),
@@ -47,6 +69,19 @@ impl EvalTree {
         if let EvalTree::Unfinished(ts) = self {
             let mut ts: Vec<_> = ts.into_iter().map(EvalTree::build).collect();
 
+            // An assignment takes everything to the right of its arrow as its value.
+            if let Some(arrow) = ts.iter().position(|t| matches!(t, EvalTree::LeftArrow)) {
+                if arrow == 0 || arrow == ts.len() - 1 {
+                    panic!("SYNTAX ERROR");
+                }
+
+                let value = EvalTree::Unfinished(ts.split_off(arrow + 1)).build();
+                ts.pop();
+                let name = ts.pop().unwrap();
+
+                ts.push(EvalTree::Assignment(Box::new(name), Box::new(value)));
+            }
+
             while ts.len() > 1 {
                 let strengths = binding_strengths(&ts);
 
@@ -63,7 +98,15 @@ impl EvalTree {
                 ts.insert(strongest, combine(x, y));
             }
 
-            ts[0].clone()
+            // A parenthesised train is a single tine of any train around it, and likewise a
+            // parenthesised strand is a single item.
+            match ts.remove(0) {
+                t @ (EvalTree::Atop(_, _) | EvalTree::Fork(_, _, _)) => {
+                    EvalTree::Function(Box::new(t))
+                }
+                t @ EvalTree::Array(_) => EvalTree::Isolated(Box::new(t)),
+                t => t,
+            }
         } else {
             self
         }
@@ -2,8 +2,11 @@ use crate::primitives::{PrimitiveDyadicOperator, PrimitiveFunction, PrimitiveMon
 
 #[derive(Debug)]
 pub enum Expr {
-    Integer(i64),
-    Float(f64),
+    Number(String),
+    String(String),
+    SystemName(String),
+    Zilde,
+    LeftArrow,
     PrimitiveFunction(PrimitiveFunction),
     PrimitiveMonadicOperator(PrimitiveMonadicOperator),
     PrimitiveDyadicOperator(PrimitiveDyadicOperator),
@@ -1,9 +1,12 @@
 use std::io::{BufRead, Write};
 
-use logos::Logos;
 use parser::parse;
 
-use crate::{eval_tree::build_tree, token::Token, value::eval};
+use crate::{
+    eval_tree::{build_tree, EvalTree},
+    token::tokenize,
+    value::{array::format::set_boxed, eval, system, Value},
+};
 
 pub mod eval_tree;
 pub mod expr;
@@ -13,6 +16,8 @@ pub mod token;
 pub mod value;
 
 fn main() {
+    system::connect();
+
     let stdin = std::io::stdin();
     let stdout = std::io::stdout();
     let mut out = stdout.lock();
@@ -21,7 +26,47 @@ fn main() {
     out.flush().unwrap();
 
     for line in stdin.lock().lines() {
-        let tokens = Token::lexer(&line.unwrap()).collect::<Vec<_>>();
+        let line = line.unwrap();
+
+        // `]box` switches between boxed and plain display of arrays, `]bigint` between exact
+        // integers of any size and promotion to float on overflow, and `]rational` between exact
+        // ratios and floats for division of integers.
+        let command = line.split_whitespace().next().unwrap_or("");
+        let switch: Option<fn(bool)> = match command {
+            "]box" => Some(set_boxed),
+            "]bigint" => Some(system::set_big_integers),
+            "]rational" => Some(system::set_rationals),
+            _ => None,
+        };
+        if let Some(switch) = switch {
+            match line.split_whitespace().nth(1) {
+                Some("on") => switch(true),
+                Some("off") => switch(false),
+                _ => println!("{} on|off", command),
+            }
+            print!("   ");
+            out.flush().unwrap();
+            continue;
+        }
+
+        // `]display` shows a function as a tree instead of in its linear form.
+        let (line, as_tree) = match line.trim_start().strip_prefix("]display") {
+            Some(rest) => (rest.to_string(), true),
+            None => (line, false),
+        };
+
+        let tokens = match tokenize(&line) {
+            Ok(tokens) => tokens,
+            Err(error) => {
+                let column = line[..error.span.start].chars().count();
+                println!("{}", error);
+                println!("      {}", line);
+                println!("      {}^", " ".repeat(column));
+                print!("   ");
+                out.flush().unwrap();
+                continue;
+            }
+        };
 
         for token in &tokens {
             print!("{}", token);
@@ -42,7 +87,12 @@ fn main() {
 
         // println!("{:#?}", result);
 
-        println!("{}", result);
+        // The result of an assignment is not shown.
+        match result {
+            _ if matches!(tree, EvalTree::Assignment(_, _)) => {}
+            Value::Function(f) if as_tree => println!("{}", f.tree()),
+            result => println!("{}", result),
+        }
         print!("   ");
         out.flush().unwrap();
     }
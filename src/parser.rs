@@ -1,10 +1,11 @@
 use combine::{
-    between, choice, many1, parser, satisfy, token, EasyParser, ParseError, Parser, Stream,
+    between, choice, many1, parser, satisfy, satisfy_map, token, EasyParser, ParseError, Parser,
+    Stream,
 };
 
 use crate::{
     expr::Expr,
-    primitives::{PrimitiveFunction, PrimitiveMonadicOperator},
+    primitives::{PrimitiveDyadicOperator, PrimitiveFunction, PrimitiveMonadicOperator},
     token::Token,
 };
 
@@ -37,6 +38,7 @@ parser! {
             parenthesized(),
             scalar(),
             primitive(),
+            token(Token::LeftArrow).map(|_| Expr::LeftArrow),
         ))
     }
 }
@@ -46,17 +48,24 @@ where
     I: Stream<Token = Token>,
     I::Error: ParseError<I::Token, I::Range, I::Position>,
 {
-    let integer = satisfy(|tok| matches!(tok, Token::Integer(_))).map(|tok| match tok {
-        Token::Integer(n) => Expr::Integer(n),
+    let number = satisfy(|tok| matches!(tok, Token::Number(_))).map(|tok| match tok {
+        Token::Number(n) => Expr::Number(n),
         _ => unreachable!(),
     });
 
-    let float = satisfy(|tok| matches!(tok, Token::Float(_))).map(|tok| match tok {
-        Token::Float(n) => Expr::Float(n),
+    let string = satisfy(|tok| matches!(tok, Token::String(_))).map(|tok| match tok {
+        Token::String(s) => Expr::String(s),
         _ => unreachable!(),
     });
 
-    choice((integer, float))
+    let system_name = satisfy(|tok| matches!(tok, Token::SystemName(_))).map(|tok| match tok {
+        Token::SystemName(name) => Expr::SystemName(name),
+        _ => unreachable!(),
+    });
+
+    let zilde = token(Token::Zilde).map(|_| Expr::Zilde);
+
+    choice((number, string, system_name, zilde))
 }
 
 fn primitive<I>() -> impl Parser<I, Output = Expr>
@@ -64,16 +73,62 @@ where
     I: Stream<Token = Token>,
     I::Error: ParseError<I::Token, I::Range, I::Position>,
 {
-    choice((
-        token(Token::Plus).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Plus)),
-        token(Token::Minus).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Minus)),
-        token(Token::Times).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Times)),
-        token(Token::Divide).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Divide)),
-        token(Token::LeftTack).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::LeftTack)),
-        token(Token::RightTack).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::RightTack)),
-        token(Token::Comma).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Comma)),
-        token(Token::Epsilon).map(|_| Expr::PrimitiveFunction(PrimitiveFunction::Epsilon)),
-        token(Token::TildeDiaeresis)
-            .map(|_| Expr::PrimitiveMonadicOperator(PrimitiveMonadicOperator::Commute)),
-    ))
+    satisfy_map(|tok| match tok {
+        Token::Plus => Some(Expr::PrimitiveFunction(PrimitiveFunction::Plus)),
+        Token::Minus => Some(Expr::PrimitiveFunction(PrimitiveFunction::Minus)),
+        Token::Times => Some(Expr::PrimitiveFunction(PrimitiveFunction::Times)),
+        Token::Divide => Some(Expr::PrimitiveFunction(PrimitiveFunction::Divide)),
+        Token::Stile => Some(Expr::PrimitiveFunction(PrimitiveFunction::Stile)),
+        Token::Circle => Some(Expr::PrimitiveFunction(PrimitiveFunction::Circle)),
+        Token::LeftTack => Some(Expr::PrimitiveFunction(PrimitiveFunction::LeftTack)),
+        Token::RightTack => Some(Expr::PrimitiveFunction(PrimitiveFunction::RightTack)),
+        Token::Comma => Some(Expr::PrimitiveFunction(PrimitiveFunction::Comma)),
+        Token::Epsilon => Some(Expr::PrimitiveFunction(PrimitiveFunction::Epsilon)),
+        Token::Rho => Some(Expr::PrimitiveFunction(PrimitiveFunction::Rho)),
+        Token::Iota => Some(Expr::PrimitiveFunction(PrimitiveFunction::Iota)),
+        Token::Question => Some(Expr::PrimitiveFunction(PrimitiveFunction::Question)),
+        Token::Domino => Some(Expr::PrimitiveFunction(PrimitiveFunction::Domino)),
+        Token::Star => Some(Expr::PrimitiveFunction(PrimitiveFunction::Star)),
+        Token::CircleStar => Some(Expr::PrimitiveFunction(PrimitiveFunction::CircleStar)),
+        Token::UpArrow => Some(Expr::PrimitiveFunction(PrimitiveFunction::UpArrow)),
+        Token::CircleStile => Some(Expr::PrimitiveFunction(PrimitiveFunction::CircleStile)),
+        Token::RightShoe => Some(Expr::PrimitiveFunction(PrimitiveFunction::RightShoe)),
+        Token::Squad => Some(Expr::PrimitiveFunction(PrimitiveFunction::Squad)),
+        Token::UpTack => Some(Expr::PrimitiveFunction(PrimitiveFunction::UpTack)),
+        Token::DownTack => Some(Expr::PrimitiveFunction(PrimitiveFunction::DownTack)),
+        Token::EqualUnderbar => Some(Expr::PrimitiveFunction(PrimitiveFunction::EqualUnderbar)),
+        Token::IotaUnderbar => Some(Expr::PrimitiveFunction(PrimitiveFunction::IotaUnderbar)),
+        Token::EpsilonUnderbar => Some(Expr::PrimitiveFunction(PrimitiveFunction::EpsilonUnderbar)),
+        Token::DownTackJot => Some(Expr::PrimitiveFunction(PrimitiveFunction::DownTackJot)),
+        Token::Slash => Some(Expr::PrimitiveMonadicOperator(
+            PrimitiveMonadicOperator::Slash,
+        )),
+        Token::Backslash => Some(Expr::PrimitiveMonadicOperator(
+            PrimitiveMonadicOperator::Backslash,
+        )),
+        Token::TildeDiaeresis => Some(Expr::PrimitiveMonadicOperator(
+            PrimitiveMonadicOperator::Commute,
+        )),
+        Token::QuadEqual => Some(Expr::PrimitiveMonadicOperator(
+            PrimitiveMonadicOperator::Key,
+        )),
+        Token::QuadDiamond => Some(Expr::PrimitiveDyadicOperator(
+            PrimitiveDyadicOperator::Stencil,
+        )),
+        Token::JotDiaeresis => Some(Expr::PrimitiveDyadicOperator(PrimitiveDyadicOperator::Atop)),
+        Token::Jot => Some(Expr::PrimitiveDyadicOperator(PrimitiveDyadicOperator::Jot)),
+        Token::CircleDiaeresis => {
+            Some(Expr::PrimitiveDyadicOperator(PrimitiveDyadicOperator::Over))
+        }
+        Token::JotUnderbar => Some(Expr::PrimitiveDyadicOperator(
+            PrimitiveDyadicOperator::Behind,
+        )),
+        Token::StarDiaeresis => Some(Expr::PrimitiveDyadicOperator(
+            PrimitiveDyadicOperator::Power,
+        )),
+        Token::DelDiaeresis => Some(Expr::PrimitiveDyadicOperator(
+            PrimitiveDyadicOperator::Under,
+        )),
+        _ => None,
+    })
 }
@@ -6,10 +6,30 @@ pub enum PrimitiveFunction {
     Minus,
     Times,
     Divide,
+    Stile,
+    Circle,
     LeftTack,
     RightTack,
     Comma,
     Epsilon,
+    Rho,
+    Iota,
+    Question,
+    Domino,
+    Star,
+    CircleStar,
+    UpArrow,
+    CircleStile,
+    RightShoe,
+    Squad,
+    UpTack,
+    DownTack,
+    EqualUnderbar,
+    IotaUnderbar,
+    EpsilonUnderbar,
+    DownTackJot,
+    Slash,
+    Backslash,
 }
 
 impl Display for PrimitiveFunction {
@@ -19,10 +39,30 @@ impl Display for PrimitiveFunction {
             PrimitiveFunction::Minus => write!(f, "-"),
             PrimitiveFunction::Times => write!(f, "×"),
             PrimitiveFunction::Divide => write!(f, "÷"),
+            PrimitiveFunction::Stile => write!(f, "|"),
+            PrimitiveFunction::Circle => write!(f, "○"),
             PrimitiveFunction::LeftTack => write!(f, "⊣"),
             PrimitiveFunction::RightTack => write!(f, "⊢"),
             PrimitiveFunction::Comma => write!(f, ","),
             PrimitiveFunction::Epsilon => write!(f, "∊"),
+            PrimitiveFunction::Rho => write!(f, "⍴"),
+            PrimitiveFunction::Iota => write!(f, "⍳"),
+            PrimitiveFunction::Question => write!(f, "?"),
+            PrimitiveFunction::Domino => write!(f, "⌹"),
+            PrimitiveFunction::Star => write!(f, "*"),
+            PrimitiveFunction::CircleStar => write!(f, "⍟"),
+            PrimitiveFunction::UpArrow => write!(f, "↑"),
+            PrimitiveFunction::CircleStile => write!(f, "⌽"),
+            PrimitiveFunction::RightShoe => write!(f, "⊃"),
+            PrimitiveFunction::Squad => write!(f, "⌷"),
+            PrimitiveFunction::UpTack => write!(f, "⊥"),
+            PrimitiveFunction::DownTack => write!(f, "⊤"),
+            PrimitiveFunction::EqualUnderbar => write!(f, "≡"),
+            PrimitiveFunction::IotaUnderbar => write!(f, "⍸"),
+            PrimitiveFunction::EpsilonUnderbar => write!(f, "⍷"),
+            PrimitiveFunction::DownTackJot => write!(f, "⍕"),
+            PrimitiveFunction::Slash => write!(f, "/"),
+            PrimitiveFunction::Backslash => write!(f, "\\"),
         }
     }
 }
@@ -30,9 +70,45 @@ impl Display for PrimitiveFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum PrimitiveMonadicOperator {
     Commute,
+    Key,
+    /// `/`, which is replicate when used as a function and reduce when used as an operator.
+    Slash,
+    /// `\`, which is expand when used as a function and scan when used as an operator.
+    Backslash,
+}
+
+impl Display for PrimitiveMonadicOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrimitiveMonadicOperator::Commute => write!(f, "⍨"),
+            PrimitiveMonadicOperator::Key => write!(f, "⌸"),
+            PrimitiveMonadicOperator::Slash => write!(f, "/"),
+            PrimitiveMonadicOperator::Backslash => write!(f, "\\"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum PrimitiveDyadicOperator {
     Atop,
+    Stencil,
+    Jot,
+    Over,
+    Behind,
+    Power,
+    Under,
+}
+
+impl Display for PrimitiveDyadicOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrimitiveDyadicOperator::Atop => write!(f, "⍤"),
+            PrimitiveDyadicOperator::Stencil => write!(f, "⌺"),
+            PrimitiveDyadicOperator::Jot => write!(f, "∘"),
+            PrimitiveDyadicOperator::Over => write!(f, "⍥"),
+            PrimitiveDyadicOperator::Behind => write!(f, "⍛"),
+            PrimitiveDyadicOperator::Power => write!(f, "⍣"),
+            PrimitiveDyadicOperator::Under => write!(f, "⍢"),
+        }
+    }
 }
@@ -1,20 +1,32 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::Range};
 
 use logos::{Lexer, Logos};
 
-#[derive(Debug, Clone, Copy, PartialEq, Logos)]
+#[derive(Debug, Clone, PartialEq, Logos)]
+#[logos(subpattern real = r"[¯_]?(([0-9]+(\.[0-9]*)?|\.[0-9]+)([eE][¯_]?[0-9]+)?|[0-9]+[rR][¯_]?[0-9]+)")]
 pub enum Token {
     // Atoms
-    #[regex(r"[¯_]?[0-9]+", parse_integer)]
-    Integer(i64),
-    #[regex(r"[¯_]?[0-9]+\.[0-9]+", parse_float)]
-    Float(f64),
+    /// A real number, which may be a ratio of integers such as `1r3`, or two real numbers joined
+    /// by `J` for a complex one. It is kept as written so that it can be read exactly in whichever
+    /// numeric representation is in use.
+    #[regex(r"(?&real)([jJ](?&real))?", |lex| lex.slice().to_string())]
+    Number(String),
+    #[regex(r"'([^']|'')*'", parse_string)]
+    String(String),
+    #[regex(r"⎕[A-Za-z]+", parse_system_name)]
+    SystemName(String),
+    #[token("⍬")]
+    #[token("`}")]
+    Zilde,
 
     // Symbols
     #[token("(")]
     LParens,
     #[token(")")]
     RParens,
+    #[token("←")]
+    #[token("`[")]
+    LeftArrow,
 
     // Primitive Functions
     #[token("+")]
@@ -27,6 +39,12 @@ pub enum Token {
     #[token("÷")]
     #[token("`=")]
     Divide,
+    #[token("|")]
+    #[token("`m")]
+    Stile,
+    #[token("○")]
+    #[token("`o")]
+    Circle,
     #[token("⊣")]
     #[token("`|")]
     LeftTack,
@@ -38,10 +56,83 @@ pub enum Token {
     #[token("`e")]
     #[token("∊")]
     Epsilon,
+    #[token("⍴")]
+    #[token("`r")]
+    Rho,
+    #[token("⍳")]
+    #[token("`i")]
+    Iota,
+    #[token("?")]
+    Question,
+    #[token("⌹")]
+    #[token("`+")]
+    Domino,
+    #[token("*")]
+    #[token("`p")]
+    Star,
+    #[token("⍟")]
+    #[token("`*")]
+    CircleStar,
+    #[token("↑")]
+    #[token("`y")]
+    UpArrow,
+    #[token("⌽")]
+    #[token("`%")]
+    CircleStile,
+    #[token("⊃")]
+    #[token("`x")]
+    RightShoe,
+    #[token("⌷")]
+    #[token("`L")]
+    Squad,
+    #[token("⊥")]
+    #[token("`b")]
+    UpTack,
+    #[token("⊤")]
+    #[token("`n")]
+    DownTack,
+    #[token("≡")]
+    #[token("`:")]
+    EqualUnderbar,
+    #[token("⍸")]
+    #[token("`I")]
+    IotaUnderbar,
+    #[token("⍷")]
+    #[token("`E")]
+    EpsilonUnderbar,
+    #[token("⍕")]
+    #[token("`'")]
+    DownTackJot,
 
     // Primitive Operators
+    #[token("/")]
+    Slash,
+    #[token("\\")]
+    Backslash,
     #[token("⍨")]
     TildeDiaeresis,
+    #[token("⌸")]
+    #[token("`K")]
+    QuadEqual,
+    #[token("⌺")]
+    #[token("`~")]
+    QuadDiamond,
+    #[token("⍤")]
+    #[token("`J")]
+    JotDiaeresis,
+    #[token("∘")]
+    #[token("`j")]
+    Jot,
+    #[token("⍥")]
+    #[token("`O")]
+    CircleDiaeresis,
+    #[token("⍛")]
+    JotUnderbar,
+    #[token("⍣")]
+    #[token("`P")]
+    StarDiaeresis,
+    #[token("⍢")]
+    DelDiaeresis,
 
     // Misc
     #[error]
@@ -53,56 +144,93 @@ impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, " ")?;
         match self {
-            Token::Integer(n) => {
-                if *n < 0 {
-                    write!(f, "¯{}", n.abs())
-                } else {
-                    write!(f, "{}", n)
-                }
-            }
-            Token::Float(n) => {
-                if *n < 0.0 {
-                    write!(f, "¯{}", n.abs())
-                } else {
-                    write!(f, "{}", n)
-                }
-            }
+            Token::Number(n) => write!(f, "{}", n),
+            Token::String(s) => write!(f, "'{}'", s.replace('\'', "''")),
+            Token::SystemName(name) => write!(f, "⎕{}", name),
+            Token::Zilde => write!(f, "⍬"),
             Token::LParens => write!(f, "("),
             Token::RParens => write!(f, ")"),
+            Token::LeftArrow => write!(f, "←"),
             Token::Plus => write!(f, "+"),
             Token::Minus => write!(f, "-"),
             Token::Times => write!(f, "×"),
             Token::Divide => write!(f, "÷"),
+            Token::Stile => write!(f, "|"),
+            Token::Circle => write!(f, "○"),
             Token::LeftTack => write!(f, "⊣"),
             Token::RightTack => write!(f, "⊢"),
             Token::Comma => write!(f, ","),
             Token::Epsilon => write!(f, "∊"),
+            Token::Rho => write!(f, "⍴"),
+            Token::Iota => write!(f, "⍳"),
+            Token::Question => write!(f, "?"),
+            Token::Domino => write!(f, "⌹"),
+            Token::Star => write!(f, "*"),
+            Token::CircleStar => write!(f, "⍟"),
+            Token::UpArrow => write!(f, "↑"),
+            Token::CircleStile => write!(f, "⌽"),
+            Token::RightShoe => write!(f, "⊃"),
+            Token::Squad => write!(f, "⌷"),
+            Token::UpTack => write!(f, "⊥"),
+            Token::DownTack => write!(f, "⊤"),
+            Token::EqualUnderbar => write!(f, "≡"),
+            Token::IotaUnderbar => write!(f, "⍸"),
+            Token::EpsilonUnderbar => write!(f, "⍷"),
+            Token::DownTackJot => write!(f, "⍕"),
+            Token::Slash => write!(f, "/"),
+            Token::Backslash => write!(f, "\\"),
             Token::TildeDiaeresis => write!(f, "⍨"),
+            Token::QuadEqual => write!(f, "⌸"),
+            Token::QuadDiamond => write!(f, "⌺"),
+            Token::JotDiaeresis => write!(f, "⍤"),
+            Token::Jot => write!(f, "∘"),
+            Token::CircleDiaeresis => write!(f, "⍥"),
+            Token::JotUnderbar => write!(f, "⍛"),
+            Token::StarDiaeresis => write!(f, "⍣"),
+            Token::DelDiaeresis => write!(f, "⍢"),
             Token::Error => Ok(()),
         }
     }
 }
 
-fn parse_integer(lex: &mut Lexer<Token>) -> Option<i64> {
-    let slice = lex.slice();
+/// A part of the source that is not a token, found where it begins.
+#[derive(Debug)]
+pub struct LexError {
+    pub span: Range<usize>,
+    pub text: String,
+}
 
-    let (negative, n): (bool, i64) = if slice.starts_with('¯') || slice.starts_with('_') {
-        (true, slice[1..].parse().ok()?)
-    } else {
-        (false, slice.parse().ok()?)
-    };
+impl Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.text.starts_with('\'') {
+            write!(f, "SYNTAX ERROR: Unpaired quote")
+        } else {
+            write!(f, "SYNTAX ERROR: Unknown character `{}`", self.text)
+        }
+    }
+}
 
-    Some(if negative { -n } else { n })
+/// Splits a line of source into tokens, stopping at the first thing that is not one.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, LexError> {
+    Token::lexer(source)
+        .spanned()
+        .map(|(token, span)| match token {
+            Token::Error => Err(LexError {
+                text: source[span.clone()].to_string(),
+                span,
+            }),
+            token => Ok(token),
+        })
+        .collect()
 }
 
-fn parse_float(lex: &mut Lexer<Token>) -> Option<f64> {
+fn parse_string(lex: &mut Lexer<Token>) -> String {
     let slice = lex.slice();
 
-    let (negative, n): (bool, f64) = if slice.starts_with('¯') || slice.starts_with('_') {
-        (true, slice[1..].parse().ok()?)
-    } else {
-        (false, slice.parse().ok()?)
-    };
+    slice[1..slice.len() - 1].replace("''", "'")
+}
 
-    Some(if negative { -n } else { n })
+/// System names are case-insensitive, so they are kept in upper case without the quad.
+fn parse_system_name(lex: &mut Lexer<Token>) -> String {
+    lex.slice().trim_start_matches('⎕').to_uppercase()
 }
@@ -0,0 +1,428 @@
+use std::{cell::Cell, fmt::Display};
+
+use super::{scalar::Scalar, Array};
+
+thread_local! {
+    static BOXED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Switches every array displayed from now on between plain APL output and boxed output.
+pub fn set_boxed(boxed: bool) {
+    BOXED.set(boxed);
+}
+
+/// A block of text, one vector of characters per line, with all lines the same width.
+type Lines = Vec<Vec<char>>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Number,
+    Character,
+    Nested,
+}
+
+impl Display for Array {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = if BOXED.get() {
+            self.boxed()
+        } else {
+            self.plain()
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", line.iter().collect::<String>().trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Array {
+    /// APL output: numbers aligned in columns, characters run together and nested items set apart
+    /// by spaces.
+    fn plain(&self) -> Lines {
+        match self {
+            Array::Scalar(Scalar::Array(a)) => pad(a.plain()),
+            Array::Scalar(s) => text(&s.to_string()),
+            _ => self.grid(Array::plain),
+        }
+    }
+
+    /// Output with every array drawn in a box, marked with its axes along the top and left edges
+    /// and with the kind of its elements along the bottom edge.
+    fn boxed(&self) -> Lines {
+        match self {
+            Array::Scalar(Scalar::Array(a)) => frame(pad(a.boxed()), '─', vec![], '∊'),
+            Array::Scalar(s) => text(&s.to_string()),
+            _ => {
+                let shape = self.shape();
+                let (leading, last) = shape.split_at(shape.len() - 1);
+
+                let axis = |n: &usize, marker| if *n == 0 { marker } else { '↓' };
+                let left = leading.iter().map(|n| axis(n, '⌽')).collect();
+                let top = if last[0] == 0 { '⊖' } else { '→' };
+
+                // An empty array is marked by the kind of its prototype.
+                let kinds: Vec<Kind> = match self.elements() {
+                    elements if elements.is_empty() => vec![kind(&self.prototype())],
+                    elements => elements.iter().map(kind).collect(),
+                };
+                let bottom = if kinds.contains(&Kind::Nested) {
+                    '∊'
+                } else if kinds.iter().all(|k| *k == Kind::Character) && !kinds.is_empty() {
+                    '─'
+                } else if kinds.iter().all(|k| *k == Kind::Number) {
+                    '~'
+                } else {
+                    '+'
+                };
+
+                frame(self.grid(Array::boxed), top, left, bottom)
+            }
+        }
+    }
+
+    /// Monadic `⍕`: the plain display of the array as characters, a vector if it fits on one line
+    /// and a matrix otherwise.
+    pub fn format(&self) -> Array {
+        let simple_characters = self
+            .elements()
+            .iter()
+            .all(|e| matches!(e, Array::Scalar(Scalar::Character(_))));
+
+        if simple_characters && self.rank() > 0 {
+            return self.clone();
+        }
+
+        let lines = self.plain();
+        let shape = if self.rank() < 2 {
+            vec![width(&lines)]
+        } else {
+            vec![lines.len(), width(&lines)]
+        };
+
+        characters(shape, lines)
+    }
+
+    /// Dyadic `⍕`: formats each number in a field given by a width and a number of decimals, either
+    /// one pair for every column or a single pair for all of them. A width of zero fits the widest
+    /// number with a space before it, and negative decimals ask for exponent form with that many
+    /// significant digits.
+    pub fn format_by(&self, spec: &Array) -> Array {
+        if spec.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let spec: Vec<i64> = spec
+            .elements()
+            .iter()
+            .map(|s| match s {
+                Array::Scalar(s) => s.as_integer(),
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect();
+
+        let pairs: Vec<(usize, i64)> = match spec.len() {
+            1 => vec![(0, spec[0])],
+            n if n % 2 == 0 => spec
+                .chunks(2)
+                .map(|p| {
+                    (
+                        usize::try_from(p[0]).unwrap_or_else(|_| panic!("DOMAIN ERROR")),
+                        p[1],
+                    )
+                })
+                .collect(),
+            _ => panic!("LENGTH ERROR"),
+        };
+
+        let shape = self.shape();
+        let length = shape.last().copied().unwrap_or(1);
+
+        if pairs.len() != 1 && pairs.len() != length {
+            panic!("LENGTH ERROR");
+        }
+
+        let fields: Vec<String> = self
+            .elements()
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let decimals = pairs[if pairs.len() == 1 { 0 } else { i % length }].1;
+                let x = match e {
+                    // Decimals are written from their own digits so that none are lost.
+                    Array::Scalar(Scalar::Decimal(d)) if decimals >= 0 => {
+                        let decimals = decimals as usize;
+                        let rounded = d.abs().round(decimals as i16);
+                        let zeros = decimals as i64 - rounded.fractional_digits_count() as i64;
+                        let digits =
+                            format!("{}{}", rounded.digits(), "0".repeat(zeros.max(0) as usize));
+                        let text = point(&digits, decimals);
+                        return if d.is_negative() {
+                            format!("¯{}", text)
+                        } else {
+                            text
+                        };
+                    }
+                    Array::Scalar(
+                        s @ (Scalar::Integer(_)
+                        | Scalar::BigInteger(_)
+                        | Scalar::Rational(_)
+                        | Scalar::Float(_)
+                        | Scalar::Decimal(_)),
+                    ) => s.as_float(),
+                    _ => panic!("DOMAIN ERROR"),
+                };
+
+                let text = if decimals < 0 {
+                    let digits = (-decimals) as usize;
+                    let scientific = format!("{:.*e}", digits - 1, x.abs());
+                    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+                    format!("{}E{}", mantissa, exponent.replace('-', "¯"))
+                } else {
+                    fixed(x.abs(), decimals as usize)
+                };
+
+                if x < 0.0 {
+                    format!("¯{}", text)
+                } else {
+                    text
+                }
+            })
+            .collect();
+
+        // A single pair fits every column to the widest number of all.
+        let fit = |fields: &mut dyn Iterator<Item = &String>| {
+            fields.map(|f| f.chars().count()).max().unwrap_or(0) + 1
+        };
+        let widths: Vec<usize> = (0..length)
+            .map(|column| match pairs.as_slice() {
+                [(0, _)] => fit(&mut fields.iter()),
+                [(width, _)] => *width,
+                pairs => match pairs[column].0 {
+                    0 => fit(&mut fields.iter().skip(column).step_by(length.max(1))),
+                    width => width,
+                },
+            })
+            .collect();
+
+        let lines: Lines = fields
+            .chunks(length.max(1))
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .flat_map(|(field, width)| {
+                        let n = field.chars().count();
+                        if n > *width {
+                            vec!['*'; *width]
+                        } else {
+                            let mut cell = vec![' '; width - n];
+                            cell.extend(field.chars());
+                            cell
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut result_shape: Vec<usize> = shape
+            .iter()
+            .take(shape.len().saturating_sub(1))
+            .copied()
+            .collect();
+        result_shape.push(widths.iter().sum());
+
+        characters(result_shape, lines)
+    }
+
+    /// Lays out the elements of an array of rank one or more as rows of aligned columns, with a
+    /// blank line between the planes of higher-rank arrays.
+    fn grid(&self, item: fn(&Array) -> Lines) -> Lines {
+        let shape = self.shape();
+        let length = shape[shape.len() - 1];
+        let elements = self.elements();
+
+        if elements.is_empty() {
+            return vec![vec![]];
+        }
+
+        let cells: Vec<(Lines, Kind)> = elements
+            .iter()
+            .map(|e| match e {
+                Array::Scalar(Scalar::Array(a)) => (pad(item(a)), Kind::Nested),
+                Array::Scalar(_) => (item(e), kind(e)),
+                a => (pad(item(a)), Kind::Nested),
+            })
+            .collect();
+
+        let columns: Vec<(usize, Kind)> = (0..length)
+            .map(|column| {
+                let column: Vec<&(Lines, Kind)> =
+                    cells.iter().skip(column).step_by(length).collect();
+                let width = column.iter().map(|(l, _)| width(l)).max().unwrap_or(0);
+                let kind = if column.iter().all(|(_, k)| *k == Kind::Character) {
+                    Kind::Character
+                } else if column.iter().any(|(_, k)| *k == Kind::Nested) {
+                    Kind::Nested
+                } else {
+                    Kind::Number
+                };
+
+                (width, kind)
+            })
+            .collect();
+
+        let rows = elements.len() / length;
+        let mut lines = vec![];
+
+        for (r, row) in cells.chunks(length).enumerate() {
+            let height = row.iter().map(|(l, _)| l.len()).max().unwrap_or(1);
+
+            for y in 0..height {
+                let mut line = vec![];
+
+                for (c, ((cell, kind), (width, column_kind))) in
+                    row.iter().zip(&columns).enumerate()
+                {
+                    if c > 0 {
+                        let previous = columns[c - 1].1;
+                        let joined = previous == *column_kind
+                            && matches!(previous, Kind::Character | Kind::Nested);
+                        if !joined {
+                            line.push(' ');
+                        }
+                    }
+
+                    let content = cell.get(y).cloned().unwrap_or_default();
+                    let padding = std::iter::repeat_n(' ', width - content.len());
+
+                    if *kind == Kind::Number {
+                        line.extend(padding);
+                        line.extend(content);
+                    } else {
+                        line.extend(content);
+                        line.extend(padding);
+                    }
+                }
+
+                lines.push(line);
+            }
+
+            // Each axis but the last two whose cells end here is marked by a blank line.
+            if r + 1 < rows {
+                let blanks = (0..shape.len() - 2)
+                    .filter(|k| {
+                        (r + 1) % shape[k + 1..shape.len() - 1].iter().product::<usize>() == 0
+                    })
+                    .count();
+                lines.extend(std::iter::repeat_n(vec![], blanks));
+            }
+        }
+
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        for line in &mut lines {
+            line.resize(width, ' ');
+        }
+
+        lines
+    }
+}
+
+/// Turns lines of text into a character array of the given shape.
+fn characters(shape: Vec<usize>, lines: Lines) -> Array {
+    Array::from_shape(
+        shape,
+        lines
+            .into_iter()
+            .flatten()
+            .map(|c| Array::Scalar(Scalar::Character(c)))
+            .collect(),
+    )
+    .with_prototype(Array::Scalar(Scalar::Character(' ')))
+}
+
+/// Writes a float that is not negative with the given number of decimals, rounding halves away
+/// from zero as APL does rather than to even.
+fn fixed(x: f64, decimals: usize) -> String {
+    let scaled = x * 10f64.powi(decimals as i32);
+
+    // Beyond 2*53 the float is already a whole number of the last decimal place.
+    if scaled < 2f64.powi(53) {
+        point(&(scaled.round() as u64).to_string(), decimals)
+    } else {
+        format!("{:.*}", decimals, x)
+    }
+}
+
+/// Puts a decimal point before the last few of the digits of a number.
+fn point(digits: &str, decimals: usize) -> String {
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+
+    if decimals == 0 {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+fn kind(a: &Array) -> Kind {
+    match a {
+        Array::Scalar(
+            Scalar::Integer(_)
+            | Scalar::BigInteger(_)
+            | Scalar::Rational(_)
+            | Scalar::Float(_)
+            | Scalar::Decimal(_)
+            | Scalar::Complex(_),
+        ) => Kind::Number,
+        Array::Scalar(Scalar::Character(_)) => Kind::Character,
+        _ => Kind::Nested,
+    }
+}
+
+fn text(s: &str) -> Lines {
+    vec![s.chars().collect()]
+}
+
+fn width(lines: &Lines) -> usize {
+    lines.first().map_or(0, Vec::len)
+}
+
+/// Sets a nested item apart from its neighbours with a space on either side.
+fn pad(lines: Lines) -> Lines {
+    lines
+        .into_iter()
+        .map(|line| [vec![' '], line, vec![' ']].concat())
+        .collect()
+}
+
+/// Draws a box around the lines, with `top` after the top-left corner, the `left` markers down
+/// the left edge and `bottom` after the bottom-left corner.
+fn frame(lines: Lines, top: char, left: Vec<char>, bottom: char) -> Lines {
+    let inner = width(&lines).max(1);
+    let height = lines.len().max(left.len()).max(1);
+
+    let edge = |corner, marker, end| {
+        let mut line = vec![corner, marker];
+        line.extend(std::iter::repeat_n('─', inner - 1));
+        line.push(end);
+        line
+    };
+
+    let mut framed = vec![edge('┌', top, '┐')];
+    for y in 0..height {
+        let mut line = vec![left.get(y).copied().unwrap_or('│')];
+        let mut content = lines.get(y).cloned().unwrap_or_default();
+        content.resize(inner, ' ');
+        line.extend(content);
+        line.push('│');
+        framed.push(line);
+    }
+    framed.push(edge('└', bottom, '┘'));
+
+    framed
+}
@@ -0,0 +1,159 @@
+use super::{scalar::Scalar, Array};
+
+/// A dense matrix of floats in row-major order, used for the linear algebra behind `⌹`.
+struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Scalars are treated as 1×1 matrices and vectors as single columns.
+    fn from_array(array: &Array) -> Matrix {
+        let (rows, cols) = match array.shape()[..] {
+            [] => (1, 1),
+            [rows] => (rows, 1),
+            [rows, cols] => (rows, cols),
+            _ => panic!("RANK ERROR"),
+        };
+
+        let data = array
+            .elements()
+            .iter()
+            .map(|a| match a {
+                Array::Scalar(s) => s.as_float(),
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect();
+
+        Matrix { rows, cols, data }
+    }
+
+    fn identity(n: usize) -> Matrix {
+        let mut data = vec![0.0; n * n];
+        for i in 0..n {
+            data[i * n + i] = 1.0;
+        }
+
+        Matrix {
+            rows: n,
+            cols: n,
+            data,
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> f64 {
+        self.data[row * self.cols + col]
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> &mut f64 {
+        &mut self.data[row * self.cols + col]
+    }
+
+    /// Applies the Householder reflection `I - 2vvᵀ/vᵀv`, acting on rows `k..` and columns `from..`.
+    fn reflect(&mut self, v: &[f64], k: usize, from: usize) {
+        let vv: f64 = v.iter().map(|x| x * x).sum();
+
+        for col in from..self.cols {
+            let dot: f64 = v
+                .iter()
+                .enumerate()
+                .map(|(i, x)| x * self.get(k + i, col))
+                .sum();
+            let factor = 2.0 * dot / vv;
+
+            for (i, x) in v.iter().enumerate() {
+                *self.get_mut(k + i, col) -= factor * x;
+            }
+        }
+    }
+
+    /// Finds the `x` minimising `‖ax - b‖` by Householder QR decomposition of `a`.
+    ///
+    /// This solves square systems exactly and overdetermined ones in the least-squares sense.
+    fn solve(mut a: Matrix, mut b: Matrix) -> Matrix {
+        if a.rows != b.rows {
+            panic!("LENGTH ERROR");
+        }
+
+        if a.rows < a.cols {
+            panic!("DOMAIN ERROR");
+        }
+
+        let scale = a.data.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+        let tolerance = scale * a.rows as f64 * f64::EPSILON * 16.0;
+
+        for k in 0..a.cols {
+            let mut v: Vec<f64> = (k..a.rows).map(|row| a.get(row, k)).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+            if norm <= tolerance {
+                panic!("DOMAIN ERROR");
+            }
+
+            v[0] += norm.copysign(v[0]);
+
+            a.reflect(&v, k, k);
+            b.reflect(&v, k, 0);
+        }
+
+        let mut x = Matrix {
+            rows: a.cols,
+            cols: b.cols,
+            data: vec![0.0; a.cols * b.cols],
+        };
+
+        for col in 0..b.cols {
+            for row in (0..a.cols).rev() {
+                let sum: f64 = (row + 1..a.cols)
+                    .map(|j| a.get(row, j) * x.get(j, col))
+                    .sum();
+
+                *x.get_mut(row, col) = (b.get(row, col) - sum) / a.get(row, row);
+            }
+        }
+
+        x
+    }
+
+    fn into_array(self, shape: Vec<usize>) -> Array {
+        let elements = self
+            .data
+            .into_iter()
+            .map(|x| {
+                if !x.is_finite() {
+                    panic!("DOMAIN ERROR");
+                }
+
+                Array::Scalar(Scalar::Float(x).round_whole())
+            })
+            .collect();
+
+        Array::from_shape(shape, elements)
+    }
+}
+
+impl Array {
+    /// Monadic `⌹`: the inverse of a square matrix, or the left pseudo-inverse of an
+    /// overdetermined one.
+    pub fn matrix_inverse(&self) -> Array {
+        let a = Matrix::from_array(self);
+        let identity = Matrix::identity(a.rows);
+
+        let shape = self.shape().into_iter().rev().collect();
+
+        Matrix::solve(a, identity).into_array(shape)
+    }
+
+    /// Dyadic `⌹`: the least-squares solution `x` of `ax = b`.
+    pub fn matrix_divide(b: &Array, a: &Array) -> Array {
+        let shape = a
+            .shape()
+            .into_iter()
+            .skip(1)
+            .chain(b.shape().into_iter().skip(1))
+            .collect();
+
+        Matrix::solve(Matrix::from_array(a), Matrix::from_array(b)).into_array(shape)
+    }
+}
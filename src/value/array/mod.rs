@@ -1,38 +1,136 @@
-use std::{
-    fmt::{Debug, Display},
-    iter::once,
-};
+use std::fmt::Debug;
 
 use self::scalar::Scalar;
 
+pub mod format;
+pub mod matrix;
+pub mod radix;
+pub mod random;
 pub mod scalar;
+pub mod search;
+pub mod stencil;
+pub mod structure;
 
 #[derive(Debug, Clone)]
 pub enum Array {
     Scalar(Scalar),
     Vector(Vec<Array>),
+    /// An array of rank two or higher, stored as its shape and its elements in row-major order.
+    Shaped(Vec<usize>, Vec<Array>),
+    /// An array with no elements, stored as its shape and its prototype, which is the item it is
+    /// padded with when it is extended.
+    Empty(Vec<usize>, Box<Array>),
 }
 
-impl Display for Array {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Array::Scalar(s) => write!(f, "{}", s),
-            Array::Vector(v) => {
-                write!(f, "[ ")?;
-                for a in v {
-                    write!(f, "{} ", a)?;
-                }
-                write!(f, "]")
+impl PartialEq for Array {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Array::Scalar(a), Array::Scalar(b)) => a == b,
+            _ => {
+                self.shape() == other.shape()
+                    && self
+                        .elements()
+                        .iter()
+                        .zip(other.elements().iter())
+                        .all(|(a, b)| a == b)
+                    // Empty arrays only match if their prototypes do.
+                    && (!self.elements().is_empty() || self.prototype() == other.prototype())
             }
         }
     }
 }
 
 impl Array {
+    /// Builds an array of any rank from a shape and its elements in row-major order. An empty
+    /// array built this way has a prototype of zero.
+    pub fn from_shape(shape: Vec<usize>, elements: Vec<Array>) -> Array {
+        if elements.is_empty() && !shape.is_empty() {
+            return Array::Empty(shape, Box::new(Array::Scalar(Scalar::Integer(0))));
+        }
+
+        match shape.len() {
+            0 => match elements.into_iter().next().unwrap() {
+                Array::Scalar(s) => Array::Scalar(s),
+                a => Array::Scalar(Scalar::Array(Box::new(a))),
+            },
+            1 => Array::Vector(elements),
+            _ => Array::Shaped(shape, elements),
+        }
+    }
+
+    /// Gives an empty array the given prototype, leaving any other array as it is.
+    pub fn with_prototype(self, prototype: Array) -> Array {
+        if self.rank() > 0 && self.elements().is_empty() {
+            Array::Empty(self.shape(), Box::new(prototype))
+        } else {
+            self
+        }
+    }
+
+    /// The prototype of the array: its first item with every number replaced by zero and every
+    /// character by a space, keeping the structure of any nested arrays.
+    pub fn prototype(&self) -> Array {
+        match self {
+            Array::Empty(_, prototype) => *prototype.clone(),
+            a => match a.elements().first() {
+                Some(item) => item.map_simple(&|s| match s {
+                    Scalar::Character(_) => Scalar::Character(' '),
+                    _ => Scalar::Integer(0),
+                }),
+                None => Array::Scalar(Scalar::Integer(0)),
+            },
+        }
+    }
+
+    /// Replaces every simple scalar throughout the array, keeping its structure.
+    fn map_simple(&self, f: &impl Fn(&Scalar) -> Scalar) -> Array {
+        match self {
+            Array::Scalar(Scalar::Array(a)) => {
+                Array::Scalar(Scalar::Array(Box::new(a.map_simple(f))))
+            }
+            Array::Scalar(s) => Array::Scalar(f(s)),
+            Array::Empty(shape, prototype) => {
+                Array::Empty(shape.clone(), Box::new(prototype.map_simple(f)))
+            }
+            a => Array::from_shape(
+                a.shape(),
+                a.elements().iter().map(|e| e.map_simple(f)).collect(),
+            ),
+        }
+    }
+
+    /// Sees through an enclosed scalar to the array inside it.
+    pub fn disclosed(&self) -> &Array {
+        match self {
+            Array::Scalar(Scalar::Array(a)) => a.disclosed(),
+            a => a,
+        }
+    }
+
     pub fn rank(&self) -> usize {
         match self {
             Array::Scalar(_) => 0,
             Array::Vector(_) => 1,
+            Array::Shaped(shape, _) | Array::Empty(shape, _) => shape.len(),
+        }
+    }
+
+    /// Monadic `≡`: the level of nesting, negative if the items are not all nested equally deep.
+    pub fn depth(&self) -> i64 {
+        match self {
+            Array::Scalar(Scalar::Array(a)) => 1 + a.depth().abs(),
+            Array::Scalar(_) => 0,
+            Array::Empty(_, prototype) => 1 + prototype.depth().abs(),
+            _ => {
+                let depths: Vec<i64> = self.elements().iter().map(Array::depth).collect();
+                let deepest = depths.iter().map(|d| d.abs()).max().unwrap_or(0);
+
+                if depths.iter().all(|d| *d == depths[0]) {
+                    1 + deepest
+                } else {
+                    -(1 + deepest)
+                }
+            }
         }
     }
 
@@ -40,6 +138,17 @@ impl Array {
         match self {
             Array::Scalar(_) => vec![],
             Array::Vector(v) => vec![v.len()],
+            Array::Shaped(shape, _) | Array::Empty(shape, _) => shape.clone(),
+        }
+    }
+
+    /// The elements of the array in row-major order.
+    pub fn elements(&self) -> Vec<Array> {
+        match self {
+            Array::Scalar(s) => vec![Array::Scalar(s.clone())],
+            Array::Vector(v) => v.clone(),
+            Array::Shaped(_, v) => v.clone(),
+            Array::Empty(_, _) => vec![],
         }
     }
 
@@ -51,6 +160,12 @@ impl Array {
         match self {
             Array::Scalar(_) => self,
             Array::Vector(v) => v.get(index[0]).unwrap(),
+            Array::Shaped(shape, v) => {
+                let offset: usize = index.iter().zip(strides(shape)).map(|(i, s)| i * s).sum();
+
+                v.get(offset).unwrap()
+            }
+            Array::Empty(_, _) => panic!("INDEX ERROR"),
         }
     }
 
@@ -63,36 +178,303 @@ impl Array {
             Array::Vector(v) => {
                 Array::Vector(v.iter().map(|a| a.pervade(scalar_fn.clone())).collect())
             }
+            Array::Shaped(shape, v) => Array::Shaped(
+                shape.clone(),
+                v.iter().map(|a| a.pervade(scalar_fn.clone())).collect(),
+            ),
+            // Scalar functions give numbers, so the result has the same structure as the argument
+            // with zeros for its elements.
+            Array::Empty(shape, prototype) => Array::Empty(
+                shape.clone(),
+                Box::new(prototype.map_simple(&|_| Scalar::Integer(0))),
+            ),
         }
     }
 
     pub fn ravel(&self) -> Array {
+        Array::from_shape(vec![self.elements().len()], self.elements())
+            .with_prototype(self.prototype())
+    }
+
+    /// Writes the array as an expression that evaluates back to it, as used when displaying the
+    /// array operands and tines of functions.
+    pub fn literal(&self) -> String {
         match self {
-            Array::Scalar(s) => Array::Vector(vec![Array::Scalar(s.clone())]),
-            Array::Vector(v) => Array::Vector(v.clone()),
+            Array::Scalar(Scalar::Array(a)) => a.literal(),
+            Array::Scalar(Scalar::Character(c)) => quote(&c.to_string()),
+            Array::Scalar(s) => s.to_string(),
+            Array::Empty(shape, prototype) => {
+                let simple = matches!(
+                    **prototype,
+                    Array::Scalar(Scalar::Integer(_) | Scalar::Character(_))
+                );
+                match (shape.as_slice(), prototype.as_ref()) {
+                    ([0], Array::Scalar(Scalar::Integer(_))) => "⍬".to_string(),
+                    ([0], Array::Scalar(Scalar::Character(_))) => "''".to_string(),
+                    _ => {
+                        let shape = shape
+                            .iter()
+                            .map(usize::to_string)
+                            .collect::<Vec<_>>()
+                            .join(" ");
+
+                        // A nested prototype is given as the first item of a strand.
+                        if simple {
+                            format!("({}⍴{})", shape, prototype.literal())
+                        } else {
+                            format!("({}⍴{} 0)", shape, prototype.disclosed().item_literal())
+                        }
+                    }
+                }
+            }
+            Array::Vector(v)
+                if v.len() > 1
+                    && v.iter()
+                        .all(|a| matches!(a, Array::Scalar(Scalar::Character(_)))) =>
+            {
+                quote(&v.iter().map(Array::to_string).collect::<String>())
+            }
+            Array::Vector(v) if v.len() == 1 => format!("(,{})", v[0].item_literal()),
+            Array::Vector(v) => v
+                .iter()
+                .map(Array::item_literal)
+                .collect::<Vec<_>>()
+                .join(" "),
+            Array::Shaped(shape, v) => format!(
+                "({}⍴{})",
+                Array::Vector(
+                    shape
+                        .iter()
+                        .map(|n| Array::Scalar(Scalar::Integer(*n as i64)))
+                        .collect()
+                )
+                .literal(),
+                Array::Vector(v.clone()).literal()
+            ),
         }
     }
 
-    pub fn catenate(a: Array, b: Array) -> Array {
-        match (a, b) {
-            (Array::Scalar(a), Array::Scalar(b)) => {
-                Array::Vector(vec![Array::Scalar(a), Array::Scalar(b)])
+    /// Writes an item of a vector literal, parenthesising it unless it is a simple scalar.
+    fn item_literal(&self) -> String {
+        match self.disclosed() {
+            a @ Array::Scalar(_) => a.literal(),
+            a => format!("({})", a.literal()),
+        }
+    }
+
+    /// Interprets a simple scalar or vector of non-negative integers as a shape.
+    pub fn as_shape(&self) -> Vec<usize> {
+        if self.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        self.elements()
+            .iter()
+            .map(|a| match a {
+                Array::Scalar(s) => {
+                    usize::try_from(s.as_integer()).unwrap_or_else(|_| panic!("DOMAIN ERROR"))
+                }
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect()
+    }
+
+    pub fn reshape(&self, shape: Vec<usize>) -> Array {
+        let mut source = self.elements();
+        let length = shape.iter().product();
+
+        // Reshaping empty data repeats its prototype.
+        if source.is_empty() {
+            source = vec![self.prototype()];
+        }
+
+        let elements = source.into_iter().cycle().take(length).collect();
+
+        Array::from_shape(shape, elements).with_prototype(self.prototype())
+    }
+
+    /// The cells of rank one less than the array, which are its scalars for a vector.
+    pub fn major_cells(&self) -> Vec<Array> {
+        match self {
+            Array::Scalar(_) => vec![self.clone()],
+            Array::Vector(v) => v
+                .iter()
+                .map(|a| Array::from_shape(vec![], vec![a.clone()]))
+                .collect(),
+            Array::Shaped(shape, v) => {
+                let cell_length: usize = shape[1..].iter().product();
+
+                (0..shape[0])
+                    .map(|i| {
+                        let cell = &v[i * cell_length..(i + 1) * cell_length];
+                        Array::from_shape(shape[1..].to_vec(), cell.to_vec())
+                    })
+                    .collect()
             }
-            (Array::Scalar(a), Array::Vector(b)) => {
-                Array::Vector(once(Array::Scalar(a)).chain(b.into_iter()).collect())
+            Array::Empty(shape, prototype) => (0..shape[0])
+                .map(|_| {
+                    Array::from_shape(shape[1..].to_vec(), vec![])
+                        .with_prototype(*prototype.clone())
+                })
+                .collect(),
+        }
+    }
+
+    /// Splits the array into its cells of the given rank, returning the shape of the frame
+    /// around them alongside the cells.
+    pub fn cells(&self, rank: usize) -> (Vec<usize>, Vec<Array>) {
+        let shape = self.shape();
+        let (frame, cell_shape) = shape.split_at(shape.len() - rank.min(shape.len()));
+
+        let elements = self.elements();
+        let cell_length: usize = cell_shape.iter().product();
+
+        let cells = (0..frame.iter().product())
+            .map(|i| {
+                let cell = &elements[i * cell_length..(i + 1) * cell_length];
+                Array::from_shape(cell_shape.to_vec(), cell.to_vec())
+                    .with_prototype(self.prototype())
+            })
+            .collect();
+
+        (frame.to_vec(), cells)
+    }
+
+    /// Combines arrays into one of higher rank with them as its major cells, like `↑`.
+    ///
+    /// Items of lower rank are extended with leading axes of length one and shorter items are
+    /// padded with their prototypes.
+    pub fn mix(items: Vec<Array>) -> Array {
+        let items: Vec<Array> = items
+            .into_iter()
+            .map(|a| match a {
+                Array::Scalar(Scalar::Array(a)) => *a,
+                a => a,
+            })
+            .collect();
+
+        let rank = items.iter().map(Array::rank).max().unwrap_or(0);
+        let shapes: Vec<Vec<usize>> = items
+            .iter()
+            .map(|a| {
+                let mut shape = vec![1; rank - a.rank()];
+                shape.extend(a.shape());
+                shape
+            })
+            .collect();
+
+        let cell_shape: Vec<usize> = (0..rank)
+            .map(|axis| shapes.iter().map(|s| s[axis]).max().unwrap_or(0))
+            .collect();
+        let cell_strides = strides(&cell_shape);
+        let cell_length: usize = cell_shape.iter().product();
+
+        let mut elements = Vec::with_capacity(items.len() * cell_length);
+
+        for (item, shape) in items.iter().zip(&shapes) {
+            if *shape == cell_shape {
+                elements.extend(item.elements());
+                continue;
             }
-            (Array::Vector(a), Array::Scalar(b)) => {
-                Array::Vector(a.into_iter().chain(once(Array::Scalar(b))).collect())
+
+            let item_elements = item.elements();
+            let item_strides = strides(shape);
+
+            for offset in 0..cell_length {
+                let index = unravel(offset, &cell_strides);
+
+                if index.iter().zip(shape).all(|(i, n)| i < n) {
+                    let position: usize = index.iter().zip(&item_strides).map(|(i, s)| i * s).sum();
+                    elements.push(item_elements[position].clone());
+                } else {
+                    elements.push(item.prototype());
+                }
             }
-            (Array::Vector(a), Array::Vector(b)) => {
-                Array::Vector(a.into_iter().chain(b.into_iter()).collect())
+        }
+
+        let mut shape = vec![items.len()];
+        shape.extend(cell_shape);
+
+        let prototype = items
+            .first()
+            .map_or(Array::Scalar(Scalar::Integer(0)), Array::prototype);
+
+        Array::from_shape(shape, elements).with_prototype(prototype)
+    }
+
+    /// Dyadic `,`: joins the arrays along the last axis. A scalar is extended to fill the other
+    /// axes, and an array of one rank less is joined as a single item along the last axis.
+    pub fn catenate(a: Array, b: Array) -> Array {
+        let prototype = a.prototype();
+
+        let rank = a.rank().max(b.rank());
+        if rank <= 1 {
+            let elements: Vec<Array> = a.elements().into_iter().chain(b.elements()).collect();
+            return Array::from_shape(vec![elements.len()], elements).with_prototype(prototype);
+        }
+
+        let larger = if a.rank() == rank { &a } else { &b };
+        let frame = larger.shape()[..rank - 1].to_vec();
+        let rows: usize = frame.iter().product();
+
+        // The length of each argument along the last axis, and its elements.
+        let columns = |x: &Array| match x.rank() {
+            0 => (1, vec![x.clone(); rows]),
+            r if r == rank || r == rank - 1 => {
+                if x.shape()[..rank - 1] != frame[..] {
+                    panic!("LENGTH ERROR");
+                }
+                let length = if r == rank { x.shape()[rank - 1] } else { 1 };
+                (length, x.elements())
             }
+            _ => panic!("RANK ERROR"),
+        };
+
+        let (a_length, a_elements) = columns(&a);
+        let (b_length, b_elements) = columns(&b);
+
+        let mut elements = Vec::with_capacity(rows * (a_length + b_length));
+        for row in 0..rows {
+            elements.extend_from_slice(&a_elements[row * a_length..(row + 1) * a_length]);
+            elements.extend_from_slice(&b_elements[row * b_length..(row + 1) * b_length]);
         }
+
+        let mut shape = frame;
+        shape.push(a_length + b_length);
+        Array::from_shape(shape, elements).with_prototype(prototype)
     }
 }
 
 impl FromIterator<Array> for Array {
     fn from_iter<T: IntoIterator<Item = Array>>(iter: T) -> Self {
-        Array::Vector(iter.into_iter().collect())
+        let elements: Vec<Array> = iter.into_iter().collect();
+        Array::from_shape(vec![elements.len()], elements)
+    }
+}
+
+/// Writes a string literal, doubling any quotes inside it.
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// The distance in the ravel between consecutive indices along each axis.
+fn strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (1..shape.len()).rev() {
+        strides[i - 1] = strides[i] * shape[i];
     }
+    strides
+}
+
+/// Converts an offset into the ravel back into an index along each axis.
+fn unravel(offset: usize, strides: &[usize]) -> Vec<usize> {
+    let mut remaining = offset;
+    strides
+        .iter()
+        .map(|stride| {
+            let i = remaining / stride;
+            remaining %= stride;
+            i
+        })
+        .collect()
 }
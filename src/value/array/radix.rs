@@ -0,0 +1,125 @@
+use super::{scalar::Scalar, Array};
+
+impl Array {
+    /// Dyadic `⊥`: evaluates the digits along the first axis of `digits` in the mixed radix
+    /// given by `radix`.
+    pub fn decode(radix: &Array, digits: &Array) -> Array {
+        if radix.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let mut rows: Vec<Vec<Array>> = match digits {
+            Array::Scalar(_) => vec![vec![digits.clone()]],
+            _ => digits.major_cells().iter().map(Array::elements).collect(),
+        };
+        let mut radix = radix.elements();
+
+        if radix.len() == 1 {
+            radix = vec![radix[0].clone(); rows.len()];
+        } else if rows.len() == 1 {
+            rows = vec![rows[0].clone(); radix.len()];
+        }
+
+        if radix.len() != rows.len() {
+            panic!("LENGTH ERROR");
+        }
+
+        let width = rows.first().map_or(0, Vec::len);
+        let values = (0..width)
+            .map(|column| {
+                let value = radix
+                    .iter()
+                    .zip(&rows)
+                    .fold(Scalar::Integer(0), |value, (r, row)| {
+                        match (r, &row[column]) {
+                            (Array::Scalar(r), Array::Scalar(d)) => value * r.clone() + d.clone(),
+                            _ => panic!("DOMAIN ERROR"),
+                        }
+                    });
+                Array::Scalar(value)
+            })
+            .collect();
+
+        let shape = digits.shape().into_iter().skip(1).collect();
+
+        Array::from_shape(shape, values)
+    }
+
+    /// Dyadic `⊤`: represents each element of `values` by its digits in the mixed radix given by
+    /// `radix`, laid out along a new first axis.
+    pub fn encode(radix: &Array, values: &Array) -> Array {
+        if radix.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let radix: Vec<Scalar> = radix
+            .elements()
+            .into_iter()
+            .map(|r| match r {
+                Array::Scalar(r) => r,
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect();
+
+        let mut shape = vec![radix.len()];
+        shape.extend(values.shape());
+
+        let values = values.elements();
+        let mut digits = vec![Array::Scalar(Scalar::Integer(0)); radix.len() * values.len()];
+
+        for (column, value) in values.iter().enumerate() {
+            let mut value = match value {
+                Array::Scalar(s) => s.clone(),
+                _ => panic!("DOMAIN ERROR"),
+            };
+
+            for (row, r) in radix.iter().enumerate().rev() {
+                let digit = if *r == Scalar::Integer(0) {
+                    std::mem::replace(&mut value, Scalar::Integer(0))
+                } else {
+                    let digit = value.clone() % r.clone();
+                    value = ((value - digit.clone()) / r.clone()).round_whole();
+                    digit
+                };
+
+                digits[row * values.len() + column] = Array::Scalar(digit);
+            }
+        }
+
+        Array::from_shape(shape, digits)
+    }
+
+    /// Repeats a single radix as many times as are needed to represent every element of
+    /// `values` in full, as used by monadic `⊤` and the inverse of `⊥`.
+    pub fn enough_digits(radix: &Array, values: &Array) -> Array {
+        let radix = match radix {
+            Array::Scalar(r) => r.as_integer(),
+            _ => return radix.clone(),
+        };
+
+        if radix < 2 {
+            panic!("DOMAIN ERROR");
+        }
+
+        let count = values
+            .elements()
+            .iter()
+            .map(|value| {
+                let mut value = match value {
+                    Array::Scalar(s) => s.as_float().abs().floor() as u64,
+                    _ => panic!("DOMAIN ERROR"),
+                };
+
+                let mut count = 1;
+                while value >= radix as u64 {
+                    value /= radix as u64;
+                    count += 1;
+                }
+                count
+            })
+            .max()
+            .unwrap_or(1);
+
+        Array::Vector(vec![Array::Scalar(Scalar::Integer(radix)); count])
+    }
+}
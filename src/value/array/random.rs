@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use super::{scalar::Scalar, Array};
+use crate::value::system;
+
+impl Array {
+    /// Dyadic `?`: `count` different indices drawn at random from `⍳range`.
+    pub fn deal(count: &Array, range: &Array) -> Array {
+        let (count, range) = match (count, range) {
+            (Array::Scalar(count), Array::Scalar(range)) => {
+                (count.as_integer(), range.as_integer())
+            }
+            _ => panic!("RANK ERROR"),
+        };
+
+        if count < 0 || count > range {
+            panic!("DOMAIN ERROR");
+        }
+
+        // A shuffle of the first `count` places of `⍳range`, which keeps only the places it has
+        // disturbed.
+        let origin = system::index_origin();
+        let mut moved: HashMap<i64, i64> = HashMap::new();
+
+        (0..count)
+            .map(|i| {
+                let j = i + (system::random() * (range - i) as f64) as i64;
+                let drawn = moved.get(&j).copied().unwrap_or(j);
+                moved.insert(j, moved.get(&i).copied().unwrap_or(i));
+
+                Array::Scalar(Scalar::Integer(drawn + origin))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,167 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+use super::Scalar;
+use crate::value::system;
+
+impl std::ops::Add for Scalar {
+    type Output = Scalar;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Scalar::Integer(a), Scalar::Integer(b)) => match a.checked_add(b) {
+                Some(c) => Scalar::Integer(c),
+                None => Scalar::overflowed(BigInt::from(a) + b),
+            },
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone() + s))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s + b.clone()))),
+            (a @ Scalar::Complex(_), b) | (a, b @ Scalar::Complex(_)) => {
+                Scalar::complex(a.as_complex() + b.as_complex())
+            }
+            (a, b) if a.is_integer() && b.is_integer() => Scalar::big(a.as_big() + b.as_big()),
+            (a, b) if a.is_exact() && b.is_exact() => {
+                Scalar::rational(a.as_rational() + b.as_rational())
+            }
+            (a, b) if Scalar::in_decimal(&a, &b) => {
+                Scalar::decimal(a.as_decimal() + b.as_decimal())
+            }
+            (a, b) => Scalar::Float(a.as_float() + b.as_float()),
+        }
+    }
+}
+
+impl std::ops::Sub for Scalar {
+    type Output = Scalar;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Scalar::Integer(a), Scalar::Integer(b)) => match a.checked_sub(b) {
+                Some(c) => Scalar::Integer(c),
+                None => Scalar::overflowed(BigInt::from(a) - b),
+            },
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone() - s))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s - b.clone()))),
+            (a @ Scalar::Complex(_), b) | (a, b @ Scalar::Complex(_)) => {
+                Scalar::complex(a.as_complex() - b.as_complex())
+            }
+            (a, b) if a.is_integer() && b.is_integer() => Scalar::big(a.as_big() - b.as_big()),
+            (a, b) if a.is_exact() && b.is_exact() => {
+                Scalar::rational(a.as_rational() - b.as_rational())
+            }
+            (a, b) if Scalar::in_decimal(&a, &b) => {
+                Scalar::decimal(a.as_decimal() - b.as_decimal())
+            }
+            (a, b) => Scalar::Float(a.as_float() - b.as_float()),
+        }
+    }
+}
+
+impl std::ops::Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Scalar::Integer(a), Scalar::Integer(b)) => match a.checked_mul(b) {
+                Some(c) => Scalar::Integer(c),
+                None => Scalar::overflowed(BigInt::from(a) * b),
+            },
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone() * s))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s * b.clone()))),
+            (a @ Scalar::Complex(_), b) | (a, b @ Scalar::Complex(_)) => {
+                Scalar::complex(a.as_complex() * b.as_complex())
+            }
+            (a, b) if a.is_integer() && b.is_integer() => Scalar::big(a.as_big() * b.as_big()),
+            (a, b) if a.is_exact() && b.is_exact() => {
+                Scalar::rational(a.as_rational() * b.as_rational())
+            }
+            (a, b) if Scalar::in_decimal(&a, &b) => {
+                Scalar::decimal(a.as_decimal() * b.as_decimal())
+            }
+            (a, b) => Scalar::Float(a.as_float() * b.as_float()),
+        }
+    }
+}
+
+/// `0÷0` is 1 and any other division by zero is an error. Division of integers is exact when the
+/// divisor goes into the dividend, and otherwise a ratio in rational mode, a decimal while `⎕FR` is
+/// 1287 or a float.
+impl std::ops::Div for Scalar {
+    type Output = Scalar;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone() / s))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s / b.clone()))),
+            (a, b) if b.is_zero() => {
+                if a.is_zero() {
+                    Scalar::Integer(1)
+                } else {
+                    panic!("DOMAIN ERROR")
+                }
+            }
+            (a @ Scalar::Complex(_), b) | (a, b @ Scalar::Complex(_)) => {
+                Scalar::complex(a.as_complex() / b.as_complex())
+            }
+            (a, b) if a.is_integer() && b.is_integer() => {
+                let (quotient, remainder) = a.as_big().div_rem(&b.as_big());
+
+                if remainder.is_zero() {
+                    Scalar::big(quotient)
+                } else if system::rationals() {
+                    Scalar::rational(BigRational::new(a.as_big(), b.as_big()))
+                } else if system::decimal_floats() {
+                    Scalar::decimal(a.as_decimal() / b.as_decimal())
+                } else {
+                    Scalar::Float(a.as_float() / b.as_float())
+                }
+            }
+            (a, b) if a.is_exact() && b.is_exact() => {
+                Scalar::rational(a.as_rational() / b.as_rational())
+            }
+            (a, b) if Scalar::in_decimal(&a, &b) => {
+                Scalar::decimal(a.as_decimal() / b.as_decimal())
+            }
+            (a, b) => Scalar::Float(a.as_float() / b.as_float()),
+        }
+    }
+}
+
+/// APL residue: the result takes the sign of the divisor, and a zero divisor leaves the dividend
+/// unchanged.
+impl std::ops::Rem for Scalar {
+    type Output = Scalar;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (a, Scalar::Integer(0)) => a,
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone() % s))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s % b.clone()))),
+            (a, b) if a.is_integer() && b.is_integer() => {
+                Scalar::big(a.as_big().mod_floor(&b.as_big()))
+            }
+            (a, b) if a.is_exact() && b.is_exact() => {
+                let (a, b) = (a.as_rational(), b.as_rational());
+                Scalar::rational(&a - &b * (&a / &b).floor())
+            }
+            (a, b) if Scalar::in_decimal(&a, &b) => {
+                let (a, b) = (a.as_decimal(), b.as_decimal());
+                if b.is_zero() {
+                    Scalar::Decimal(a)
+                } else {
+                    let floor = Scalar::checked_decimal(a / b).floor();
+                    Scalar::decimal(a - floor * b)
+                }
+            }
+            (a, b) => {
+                let (a, b) = (a.as_float(), b.as_float());
+                if b == 0.0 {
+                    Scalar::Float(a)
+                } else {
+                    Scalar::Float(a - b * (a / b).floor())
+                }
+            }
+        }
+    }
+}
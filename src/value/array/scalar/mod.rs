@@ -0,0 +1,640 @@
+use std::fmt::Display;
+
+use fastnum::{decimal::Context, D128};
+use num_bigint::BigInt;
+use num_complex::Complex64;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::value::system;
+
+use super::Array;
+
+mod arithmetic;
+
+#[derive(Debug, Clone)]
+pub enum Scalar {
+    Integer(i64),
+    /// An integer too large for 64 bits, which only arises in big-integer mode.
+    BigInteger(BigInt),
+    /// An exact ratio of integers, which arises from literals such as `1r3` and from division in
+    /// rational mode.
+    Rational(BigRational),
+    Float(f64),
+    /// A 128-bit decimal float, which takes the place of `Float` while `⎕FR` is 1287 so that
+    /// decimal fractions are held exactly. It carries 38 significant digits and an exponent of up
+    /// to about 32767 either way, which covers the range of IEEE decimal128.
+    Decimal(D128),
+    Complex(Complex64),
+    Character(char),
+    Array(Box<Array>),
+}
+
+impl Scalar {
+    pub fn round_whole(&self) -> Scalar {
+        match self {
+            Scalar::Integer(i) => Scalar::Integer(*i),
+            Scalar::BigInteger(b) => Scalar::big(b.clone()),
+            Scalar::Rational(r) => Scalar::rational(r.clone()),
+            Scalar::Float(f) => {
+                if *f % 1.0 == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 {
+                    Scalar::Integer(*f as i64)
+                } else {
+                    Scalar::Float(*f)
+                }
+            }
+            Scalar::Decimal(d) => match d.to_i64() {
+                Ok(i) if d.trunc() == *d => Scalar::Integer(i),
+                _ => Scalar::Decimal(*d),
+            },
+            Scalar::Complex(c) if c.im == 0.0 => Scalar::Float(c.re).round_whole(),
+            Scalar::Complex(c) => Scalar::Complex(*c),
+            Scalar::Character(c) => Scalar::Character(*c),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.round_whole()))),
+        }
+    }
+
+    /// Traps the infinities and NaNs of IEEE arithmetic, which no primitive may produce.
+    pub fn finite(self) -> Scalar {
+        match self {
+            Scalar::Float(f) if !f.is_finite() => panic!("DOMAIN ERROR"),
+            Scalar::Complex(c) if !c.is_finite() => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(Scalar::finite))),
+            x => x,
+        }
+    }
+
+    /// Whether the scalar is the number zero, in any representation.
+    fn is_zero(&self) -> bool {
+        match self {
+            Scalar::Integer(i) => *i == 0,
+            Scalar::Float(f) => *f == 0.0,
+            Scalar::Decimal(d) => d.is_zero(),
+            Scalar::Complex(c) => c.norm() == 0.0,
+            _ => false,
+        }
+    }
+
+    /// Reads a numeric literal as checked by the lexer: an integer, a decimal with an optional
+    /// exponent or a ratio of integers, or two of these joined by `J` for a complex number.
+    /// Integers too large for 64 bits are read as floats, and other numbers as decimals while `⎕FR`
+    /// is 1287. A literal out of the range of either is a DOMAIN ERROR.
+    pub fn from_literal(literal: &str) -> Scalar {
+        if let Some((re, im)) = literal.split_once(['j', 'J']) {
+            let part = |part| Scalar::from_literal(part).as_float();
+            return Scalar::complex(Complex64::new(part(re), part(im)));
+        }
+
+        let literal = literal.replace(['¯', '_'], "-");
+
+        if let Some((numerator, denominator)) = literal.split_once(['r', 'R']) {
+            let denominator: BigInt = denominator.parse().unwrap();
+            if denominator == BigInt::from(0) {
+                panic!("DOMAIN ERROR");
+            }
+            return Scalar::rational(BigRational::new(numerator.parse().unwrap(), denominator));
+        }
+
+        match (literal.parse::<i64>(), literal.parse::<BigInt>()) {
+            (Ok(i), _) => Scalar::Integer(i),
+            (_, Ok(b)) if system::big_integers() => Scalar::BigInteger(b),
+            _ if system::decimal_floats() => Scalar::Decimal(Scalar::parse_decimal(&literal)),
+            _ => Scalar::Float(literal.parse().unwrap()).finite(),
+        }
+    }
+
+    pub fn as_integer(&self) -> i64 {
+        match self.round_whole() {
+            Scalar::Integer(i) => i,
+            _ => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    pub fn as_float(&self) -> f64 {
+        match self {
+            Scalar::Integer(i) => *i as f64,
+            Scalar::BigInteger(b) => b.to_f64().unwrap(),
+            Scalar::Rational(r) => r.to_f64().unwrap(),
+            Scalar::Float(f) => *f,
+            Scalar::Decimal(d) => d.to_f64(),
+            Scalar::Complex(c) if c.im == 0.0 => c.re,
+            Scalar::Complex(_) | Scalar::Character(_) | Scalar::Array(_) => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    pub fn as_complex(&self) -> Complex64 {
+        match self {
+            Scalar::Complex(c) => *c,
+            x => Complex64::new(x.as_float(), 0.0),
+        }
+    }
+
+    fn is_real(&self) -> bool {
+        matches!(
+            self,
+            Scalar::Integer(_)
+                | Scalar::BigInteger(_)
+                | Scalar::Rational(_)
+                | Scalar::Float(_)
+                | Scalar::Decimal(_)
+        )
+    }
+
+    /// Whether arithmetic on the two numbers is done in decimal, because either of them is a
+    /// decimal or because `⎕FR` is 1287.
+    fn in_decimal(a: &Scalar, b: &Scalar) -> bool {
+        matches!(a, Scalar::Decimal(_))
+            || matches!(b, Scalar::Decimal(_))
+            || system::decimal_floats()
+    }
+
+    /// Decimal arithmetic reports overflow and the like in its results rather than panicking, so
+    /// that `checked_decimal` can raise them as APL errors.
+    fn decimal_context() -> Context {
+        Context::default().without_traps()
+    }
+
+    /// Reads a decimal written in Rust's notation.
+    fn parse_decimal(text: &str) -> D128 {
+        match D128::from_str(text, Scalar::decimal_context()) {
+            Ok(d) => Scalar::checked_decimal(d),
+            Err(_) => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    /// The number as a decimal. Floats are taken at the shortest decimal that reads back as the
+    /// same float rather than at their exact binary value.
+    fn as_decimal(&self) -> D128 {
+        let decimal = match self {
+            Scalar::Integer(i) => D128::from(*i).with_ctx(Scalar::decimal_context()),
+            Scalar::BigInteger(b) => Scalar::parse_decimal(&b.to_string()),
+            Scalar::Rational(r) => Scalar::BigInteger(r.numer().clone())
+                .as_decimal()
+                .div(Scalar::BigInteger(r.denom().clone()).as_decimal()),
+            Scalar::Float(f) if f.is_finite() => Scalar::parse_decimal(&format!("{:e}", f)),
+            Scalar::Decimal(d) => d.with_ctx(Scalar::decimal_context()),
+            Scalar::Complex(c) if c.im == 0.0 && c.re.is_finite() => {
+                Scalar::parse_decimal(&format!("{:e}", c.re))
+            }
+            _ => panic!("DOMAIN ERROR"),
+        };
+
+        Scalar::checked_decimal(decimal)
+    }
+
+    /// The result of decimal arithmetic.
+    fn decimal(result: D128) -> Scalar {
+        Scalar::Decimal(Scalar::checked_decimal(result))
+    }
+
+    /// Fails if a decimal is out of the range of decimals, including when it is too small to be
+    /// held without losing digits.
+    fn checked_decimal(result: D128) -> D128 {
+        if !result.is_finite()
+            || result.is_op_overflow()
+            || result.is_op_underflow()
+            || result.is_op_div_by_zero()
+            || result.is_op_invalid()
+        {
+            panic!("DOMAIN ERROR");
+        }
+
+        result
+    }
+
+    /// Whether the number is held exactly, as an integer or a ratio of integers.
+    fn is_exact(&self) -> bool {
+        matches!(
+            self,
+            Scalar::Integer(_) | Scalar::BigInteger(_) | Scalar::Rational(_)
+        )
+    }
+
+    fn as_rational(&self) -> BigRational {
+        match self {
+            Scalar::Rational(r) => r.clone(),
+            x => BigRational::from_integer(x.as_big()),
+        }
+    }
+
+    /// The result of rational arithmetic, which is an integer again if its denominator is one.
+    fn rational(exact: BigRational) -> Scalar {
+        if exact.is_integer() {
+            Scalar::big(exact.to_integer())
+        } else {
+            Scalar::Rational(exact)
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        matches!(self, Scalar::Integer(_) | Scalar::BigInteger(_))
+    }
+
+    fn as_big(&self) -> BigInt {
+        match self {
+            Scalar::Integer(i) => BigInt::from(*i),
+            Scalar::BigInteger(b) => b.clone(),
+            _ => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    /// An integer result that no longer fits in 64 bits, which is kept exact in big-integer mode
+    /// and becomes a float otherwise.
+    fn overflowed(exact: BigInt) -> Scalar {
+        if system::big_integers() {
+            Scalar::BigInteger(exact)
+        } else {
+            Scalar::Float(exact.to_f64().unwrap())
+        }
+    }
+
+    /// The result of big-integer arithmetic, held in 64 bits again when it fits.
+    fn big(exact: BigInt) -> Scalar {
+        match exact.to_i64() {
+            Some(i) => Scalar::Integer(i),
+            None => Scalar::BigInteger(exact),
+        }
+    }
+
+    /// A complex result, which is real again if its imaginary part is zero.
+    fn complex(c: Complex64) -> Scalar {
+        Scalar::Complex(c).round_whole()
+    }
+
+    pub fn conjugate(&self) -> Scalar {
+        match self {
+            Scalar::Integer(i) => Scalar::Integer(*i),
+            Scalar::BigInteger(b) => Scalar::BigInteger(b.clone()),
+            Scalar::Rational(r) => Scalar::Rational(r.clone()),
+            Scalar::Float(f) => Scalar::Float(*f),
+            Scalar::Decimal(d) => Scalar::Decimal(*d),
+            Scalar::Complex(c) => Scalar::complex(c.conj()),
+            Scalar::Character(_) => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.conjugate()))),
+        }
+    }
+
+    pub fn negation(&self) -> Scalar {
+        match self {
+            Scalar::Integer(i) => match i.checked_neg() {
+                Some(i) => Scalar::Integer(i),
+                None => Scalar::overflowed(-BigInt::from(*i)),
+            },
+            Scalar::BigInteger(b) => Scalar::big(-b),
+            Scalar::Rational(r) => Scalar::Rational(-r),
+            Scalar::Float(f) => Scalar::Float(-*f),
+            Scalar::Decimal(d) => Scalar::Decimal(d.neg()),
+            Scalar::Complex(c) => Scalar::Complex(-*c),
+            Scalar::Character(_) => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.negation()))),
+        }
+    }
+
+    pub fn reciprocal(&self) -> Scalar {
+        match self {
+            x if x.is_zero() => panic!("DOMAIN ERROR"),
+            x if x.is_exact() && (system::rationals() || matches!(x, Scalar::Rational(_))) => {
+                Scalar::rational(x.as_rational().recip())
+            }
+            Scalar::Decimal(d) => {
+                Scalar::decimal(Scalar::Integer(1).as_decimal() / *d).round_whole()
+            }
+            x if system::decimal_floats() && x.is_real() => {
+                Scalar::decimal(Scalar::Integer(1).as_decimal() / x.as_decimal()).round_whole()
+            }
+            Scalar::Integer(_) | Scalar::BigInteger(_) | Scalar::Rational(_) => {
+                Scalar::Float(1.0 / self.as_float()).round_whole()
+            }
+            Scalar::Float(f) => Scalar::Float(1.0 / *f).round_whole(),
+            Scalar::Complex(c) => Scalar::complex(c.inv()),
+            Scalar::Character(_) => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.reciprocal()))),
+        }
+    }
+
+    pub fn exponential(&self) -> Scalar {
+        match self {
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.exponential()))),
+            Scalar::Complex(c) => Scalar::complex(c.exp()),
+            x => Scalar::Float(x.as_float().exp()),
+        }
+    }
+
+    pub fn natural_logarithm(&self) -> Scalar {
+        match self {
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.natural_logarithm()))),
+            x => match x.as_complex() {
+                c if c.im == 0.0 && c.re >= 0.0 => Scalar::Float(c.re.ln()),
+                c => Scalar::complex(c.ln()),
+            },
+        }
+    }
+
+    pub fn power(self, exponent: Scalar) -> Scalar {
+        match (self, exponent) {
+            (Scalar::Integer(a), Scalar::Integer(b))
+                if u32::try_from(b).is_ok_and(|b| a.checked_pow(b).is_some()) =>
+            {
+                Scalar::Integer(a.pow(b as u32))
+            }
+            (a, Scalar::Integer(b))
+                if a.is_integer()
+                    && u32::try_from(b).is_ok()
+                    && (system::big_integers() || matches!(a, Scalar::BigInteger(_))) =>
+            {
+                Scalar::big(num_traits::Pow::pow(a.as_big(), b as u32))
+            }
+            (a, Scalar::Integer(b))
+                if a.is_exact()
+                    && i32::try_from(b).is_ok()
+                    && (system::rationals() || matches!(a, Scalar::Rational(_)))
+                    && !(a.as_rational() == BigRational::from_integer(BigInt::from(0))
+                        && b < 0) =>
+            {
+                Scalar::rational(num_traits::Pow::pow(a.as_rational(), b as i32))
+            }
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone().power(s)))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s.power(b.clone())))),
+            (a, Scalar::Integer(b)) if Scalar::in_decimal(&a, &a) && a.is_real() => {
+                let b = i32::try_from(b).unwrap_or_else(|_| panic!("DOMAIN ERROR"));
+                Scalar::decimal(a.as_decimal().powi(b))
+            }
+            (a, b)
+                if a.is_real()
+                    && b.is_real()
+                    && (a.as_float() >= 0.0 || b.as_float().fract() == 0.0) =>
+            {
+                Scalar::Float(a.as_float().powf(b.as_float()))
+            }
+            (a, b) => match (a.as_complex(), b.as_complex()) {
+                (a, b) if a == Complex64::new(0.0, 0.0) => {
+                    if b.re > 0.0 {
+                        Scalar::Integer(0)
+                    } else {
+                        panic!("DOMAIN ERROR")
+                    }
+                }
+                // Whole powers and square roots are worked out directly, as `powc` goes through
+                // logarithms and leaves rounding noise in parts that should be zero.
+                (a, b) if b.im == 0.0 && b.re.fract() == 0.0 && b.re.abs() <= i32::MAX as f64 => {
+                    Scalar::complex(a.powi(b.re as i32))
+                }
+                (a, b) if b == Complex64::new(0.5, 0.0) => Scalar::complex(a.sqrt()),
+                (a, b) => Scalar::complex(a.powc(b)),
+            },
+        }
+    }
+
+    pub fn logarithm(self, base: Scalar) -> Scalar {
+        match (self, base) {
+            (a, Scalar::Array(b)) => Scalar::Array(Box::new(b.pervade(|s| a.clone().logarithm(s)))),
+            (Scalar::Array(a), b) => Scalar::Array(Box::new(a.pervade(|s| s.logarithm(b.clone())))),
+            (a, b) => match (a.as_complex(), b.as_complex()) {
+                (a, b) if a.im == 0.0 && b.im == 0.0 && a.re >= 0.0 && b.re >= 0.0 => {
+                    Scalar::Float(a.re.ln() / b.re.ln())
+                }
+                (a, b) => Scalar::complex(a.ln() / b.ln()),
+            },
+        }
+    }
+
+    pub fn signum(&self) -> Scalar {
+        match self {
+            Scalar::Integer(i) => Scalar::Integer(i.signum()),
+            Scalar::BigInteger(b) => Scalar::Integer(if b.is_negative() { -1 } else { 1 }),
+            Scalar::Rational(r) => Scalar::Integer(if r.is_negative() { -1 } else { 1 }),
+            x if x.is_zero() => Scalar::Integer(0),
+            Scalar::Float(f) => Scalar::Integer(if *f > 0.0 { 1 } else { -1 }),
+            Scalar::Decimal(d) => Scalar::Integer(if d.is_negative() { -1 } else { 1 }),
+            Scalar::Complex(c) => Scalar::complex(c / c.norm()),
+            Scalar::Character(_) => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.signum()))),
+        }
+    }
+
+    /// Monadic `|`: the distance from zero.
+    pub fn magnitude(&self) -> Scalar {
+        match self {
+            Scalar::Integer(i) => match i.checked_abs() {
+                Some(i) => Scalar::Integer(i),
+                None => Scalar::overflowed(BigInt::from(*i).abs()),
+            },
+            Scalar::BigInteger(b) => Scalar::BigInteger(b.abs()),
+            Scalar::Rational(r) => Scalar::Rational(r.abs()),
+            Scalar::Float(f) => Scalar::Float(f.abs()),
+            Scalar::Decimal(d) => Scalar::Decimal(d.abs()),
+            Scalar::Complex(c) => Scalar::Float(c.norm()),
+            Scalar::Character(_) => panic!("DOMAIN ERROR"),
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.magnitude()))),
+        }
+    }
+
+    /// Monadic `○`: pi times the number.
+    pub fn pi_times(&self) -> Scalar {
+        match self {
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.pi_times()))),
+            Scalar::Complex(c) => Scalar::complex(c * std::f64::consts::PI),
+            x => Scalar::Float(x.as_float() * std::f64::consts::PI),
+        }
+    }
+
+    /// Monadic `?`: an integer drawn at random from `⍳n`, or a float between 0 and 1 for 0.
+    pub fn roll(&self) -> Scalar {
+        match self {
+            Scalar::Array(a) => Scalar::Array(Box::new(a.pervade(|x| x.roll()))),
+            x => match x.as_integer() {
+                0 => Scalar::Float(system::random()),
+                n if n > 0 => {
+                    Scalar::Integer((system::random() * n as f64) as i64 + system::index_origin())
+                }
+                _ => panic!("DOMAIN ERROR"),
+            },
+        }
+    }
+
+    /// Dyadic `○`: the circular, hyperbolic or complex-part function selected by `k`, whose
+    /// negation selects the inverse function.
+    pub fn circle(self, k: Scalar) -> Scalar {
+        let (x, k) = match (self, k) {
+            (a, Scalar::Array(k)) => {
+                return Scalar::Array(Box::new(k.pervade(|k| a.clone().circle(k))))
+            }
+            (Scalar::Array(a), k) => {
+                return Scalar::Array(Box::new(a.pervade(|x| x.circle(k.clone()))))
+            }
+            (x, k) => (x.as_complex(), k.as_integer()),
+        };
+
+        let one = Complex64::new(1.0, 0.0);
+        let i = Complex64::new(0.0, 1.0);
+
+        Scalar::complex(match k {
+            0 => (one - x * x).sqrt(),
+            1 => x.sin(),
+            2 => x.cos(),
+            3 => x.tan(),
+            4 => (one + x * x).sqrt(),
+            5 => x.sinh(),
+            6 => x.cosh(),
+            7 => x.tanh(),
+            8 => (-one - x * x).sqrt(),
+            9 => Complex64::new(x.re, 0.0),
+            10 => Complex64::new(x.norm(), 0.0),
+            11 => Complex64::new(x.im, 0.0),
+            12 => Complex64::new(x.arg(), 0.0),
+            -1 => x.asin(),
+            -2 => x.acos(),
+            -3 => x.atan(),
+            -4 if x == -one => Complex64::new(0.0, 0.0),
+            -4 => (x + one) * ((x - one) / (x + one)).sqrt(),
+            -5 => x.asinh(),
+            -6 => x.acosh(),
+            -7 => x.atanh(),
+            -8 => -(-one - x * x).sqrt(),
+            -9 => x,
+            -10 => x.conj(),
+            -11 => i * x,
+            -12 => (i * x).exp(),
+            _ => panic!("DOMAIN ERROR"),
+        })
+    }
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Scalar::Integer(a), Scalar::Integer(b)) => a == b,
+            (a, b) if a.is_exact() && b.is_exact() => a.as_rational() == b.as_rational(),
+            (Scalar::Character(a), Scalar::Character(b)) => a == b,
+            (Scalar::Complex(a), b) | (b, Scalar::Complex(a)) => {
+                let b = match b {
+                    Scalar::Character(_) | Scalar::Array(_) => return false,
+                    b => b.as_complex(),
+                };
+                (a - b).norm() <= system::comparison_tolerance() * a.norm().max(b.norm())
+            }
+            (Scalar::Array(a), Scalar::Array(b)) => a == b,
+            (Scalar::Array(a), b) | (b, Scalar::Array(a)) => **a == Array::Scalar(b.clone()),
+            (Scalar::Character(_), _) | (_, Scalar::Character(_)) => false,
+            (a @ Scalar::Decimal(_), b) | (b, a @ Scalar::Decimal(_)) => {
+                let (a, b) = (a.as_decimal(), b.as_decimal());
+                let tolerance = Scalar::Float(system::comparison_tolerance()).as_decimal();
+                (a - b).abs() <= tolerance * a.abs().max(b.abs())
+            }
+            (a, b) => {
+                let (a, b) = (a.as_float(), b.as_float());
+                a == b || (a - b).abs() <= system::comparison_tolerance() * a.abs().max(b.abs())
+            }
+        }
+    }
+}
+
+impl Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scalar::Integer(n) => {
+                if *n < 0 {
+                    write!(f, "¯{}", n.abs())
+                } else {
+                    write!(f, "{}", n)
+                }
+            }
+            Scalar::Float(n) => write!(f, "{}", format_float(*n, system::print_precision())),
+            Scalar::Decimal(d) => write!(f, "{}", format_decimal(*d, system::print_precision())),
+            Scalar::BigInteger(b) => write!(f, "{}", b.to_string().replace('-', "¯")),
+            Scalar::Rational(r) => write!(
+                f,
+                "{}r{}",
+                r.numer().to_string().replace('-', "¯"),
+                r.denom()
+            ),
+            Scalar::Complex(c) => write!(
+                f,
+                "{}J{}",
+                format_float(c.re, system::print_precision()),
+                format_float(c.im, system::print_precision())
+            ),
+            Scalar::Character(c) => write!(f, "{}", c),
+            Scalar::Array(a) => write!(f, "{}", a),
+        }
+    }
+}
+
+/// Writes a float rounded to the given number of significant digits, at most the 17 that doubles
+/// carry, switching to exponent form when it is too large to show that way or smaller than
+/// `0.00001`.
+pub fn format_float(x: f64, digits: usize) -> String {
+    let digits = digits.min(17);
+
+    if x == 0.0 {
+        return "0".to_string();
+    }
+
+    let scientific = format!("{:.*e}", digits - 1, x.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+
+    place(
+        x < 0.0,
+        &mantissa.replace('.', ""),
+        exponent.parse().unwrap(),
+        digits,
+    )
+}
+
+/// Writes a decimal rounded to the given number of significant digits in the same way as a float.
+pub fn format_decimal(x: D128, digits: usize) -> String {
+    if x.is_zero() {
+        return "0".to_string();
+    }
+
+    let excess = x.digits_count() as i16 - digits as i16;
+    let rounded = if excess > 0 {
+        x.round(x.fractional_digits_count() - excess)
+    } else {
+        x
+    };
+    let significand = rounded.digits().to_string();
+    let exponent = significand.len() as i32 - 1 - rounded.fractional_digits_count() as i32;
+
+    place(x.is_negative(), &significand, exponent, digits)
+}
+
+/// Places the decimal point in the significant digits of a number whose leading digit is in the
+/// column of the given power of ten, using exponent form if the number is at least `10*digits` or
+/// less than `0.00001`.
+fn place(negative: bool, significand: &str, exponent: i32, digits: usize) -> String {
+    let sign = if negative { "¯" } else { "" };
+    let significand = significand.trim_end_matches('0');
+
+    if exponent < -5 || exponent >= digits as i32 {
+        let (first, rest) = significand.split_at(1);
+        let mantissa = if rest.is_empty() {
+            first.to_string()
+        } else {
+            format!("{}.{}", first, rest)
+        };
+        let exponent = if exponent < 0 {
+            format!("¯{}", -exponent)
+        } else {
+            exponent.to_string()
+        };
+
+        format!("{}{}E{}", sign, mantissa, exponent)
+    } else if exponent < 0 {
+        format!(
+            "{}0.{}{}",
+            sign,
+            "0".repeat((-exponent - 1) as usize),
+            significand
+        )
+    } else {
+        let whole = exponent as usize + 1;
+
+        if significand.len() > whole {
+            let (whole, fraction) = significand.split_at(whole);
+            format!("{}{}.{}", sign, whole, fraction)
+        } else {
+            format!("{}{:0<whole$}", sign, significand)
+        }
+    }
+}
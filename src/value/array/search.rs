@@ -0,0 +1,170 @@
+use super::{scalar::Scalar, strides, unravel, Array};
+use crate::value::system;
+
+impl Array {
+    /// Monadic `⍸`: the indices of the array, each repeated as many times as the count held there.
+    pub fn where_indices(&self) -> Array {
+        let origin = system::index_origin();
+        let shape = self.shape();
+        let mut indices = vec![];
+
+        for (offset, element) in self.elements().iter().enumerate() {
+            let count = match element {
+                Array::Scalar(s @ (Scalar::Integer(_) | Scalar::Float(_))) => {
+                    usize::try_from(s.as_integer()).unwrap_or_else(|_| panic!("DOMAIN ERROR"))
+                }
+                _ => panic!("DOMAIN ERROR"),
+            };
+
+            if count == 0 {
+                continue;
+            }
+
+            let index = if shape.len() == 1 {
+                Array::Scalar(Scalar::Integer(offset as i64 + origin))
+            } else {
+                unravel(offset, &strides(&shape))
+                    .into_iter()
+                    .map(|i| Array::Scalar(Scalar::Integer(i as i64 + origin)))
+                    .collect()
+            };
+
+            indices.extend(std::iter::repeat_n(index, count));
+        }
+
+        indices.into_iter().collect()
+    }
+
+    /// Dyadic `⍸`: for each element of `values`, the index of the interval between boundaries that
+    /// it falls into, where the interval before the first boundary is `⎕IO-1`.
+    pub fn interval_index(boundaries: &Array, values: &Array) -> Array {
+        if boundaries.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let boundaries: Vec<f64> = boundaries.elements().iter().map(as_number).collect();
+
+        if boundaries.windows(2).any(|w| w[0] > w[1]) {
+            panic!("DOMAIN ERROR");
+        }
+
+        let origin = system::index_origin();
+
+        values.pervade(|s| {
+            let x = s.as_float();
+            Scalar::Integer(boundaries.partition_point(|b| *b <= x) as i64 + origin - 1)
+        })
+    }
+
+    /// Monadic `⍳`: the indices of an array of the given shape, counted from `⎕IO`. The indices
+    /// are numbers for a scalar or one-item shape, and vectors for longer shapes.
+    pub fn index_generator(&self) -> Array {
+        let shape = self.as_shape();
+        let origin = system::index_origin();
+        let index_strides = strides(&shape);
+
+        let indices = (0..shape.iter().product())
+            .map(|offset| {
+                let mut index: Vec<Array> = unravel(offset, &index_strides)
+                    .into_iter()
+                    .map(|i| Array::Scalar(Scalar::Integer(i as i64 + origin)))
+                    .collect();
+
+                if shape.len() == 1 {
+                    index.remove(0)
+                } else {
+                    index.into_iter().collect()
+                }
+            })
+            .collect();
+
+        Array::from_shape(shape.clone(), indices)
+    }
+
+    /// Dyadic `⍳`: for each cell of `values` the size of a major cell of `table`, the index of its
+    /// first occurrence among those major cells, or one past the last index if it is missing.
+    pub fn index_of(table: &Array, values: &Array) -> Array {
+        if table.rank() == 0 {
+            panic!("RANK ERROR");
+        }
+
+        let origin = system::index_origin();
+        let cells = table.major_cells();
+        let (frame, wanted) = values.cells(table.rank() - 1);
+
+        let indices = wanted
+            .iter()
+            .map(|x| {
+                let x = x.disclosed();
+                let i = cells.iter().position(|c| c.disclosed() == x);
+                Array::Scalar(Scalar::Integer(i.unwrap_or(cells.len()) as i64 + origin))
+            })
+            .collect();
+
+        Array::from_shape(frame, indices)
+    }
+
+    /// Dyadic `⍷`: a boolean mask of the positions in `haystack` where an occurrence of `needle`
+    /// begins.
+    pub fn find(needle: &Array, haystack: &Array) -> Array {
+        let shape = haystack.shape();
+        let elements = haystack.elements();
+
+        let zeros = || {
+            Array::from_shape(
+                shape.clone(),
+                vec![Array::Scalar(Scalar::Integer(0)); elements.len()],
+            )
+        };
+
+        if needle.rank() > haystack.rank() {
+            return zeros();
+        }
+
+        let mut needle_shape = vec![1; shape.len() - needle.rank()];
+        needle_shape.extend(needle.shape());
+        let needle = needle.elements();
+
+        if needle_shape.iter().zip(&shape).any(|(n, h)| n > h) {
+            return zeros();
+        }
+
+        let haystack_strides = strides(&shape);
+        let needle_strides = strides(&needle_shape);
+
+        let mask = (0..elements.len())
+            .map(|offset| {
+                let start = unravel(offset, &haystack_strides);
+
+                let fits = start
+                    .iter()
+                    .zip(&needle_shape)
+                    .zip(&shape)
+                    .all(|((s, n), h)| s + n <= *h);
+
+                let found = fits
+                    && needle.iter().enumerate().all(|(i, x)| {
+                        let position: usize = unravel(i, &needle_strides)
+                            .iter()
+                            .zip(&start)
+                            .zip(&haystack_strides)
+                            .map(|((n, s), stride)| (n + s) * stride)
+                            .sum();
+
+                        elements[position] == *x
+                    });
+
+                Array::Scalar(Scalar::Integer(found as i64))
+            })
+            .collect();
+
+        Array::from_shape(shape, mask)
+    }
+}
+
+fn as_number(a: &Array) -> f64 {
+    match a {
+        Array::Scalar(s) => s.as_float(),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
@@ -0,0 +1,100 @@
+use super::{scalar::Scalar, strides, unravel, Array};
+
+impl Array {
+    /// Cuts the array into the moving windows used by `⌺`, centred on every `step`th position of
+    /// the leading axes and padded with zeros past the edges.
+    ///
+    /// Returns the shape of the frame of windows and, for each window, the amount of padding along
+    /// each axis (positive at the start, negative at the end) together with the window itself.
+    pub fn windows(&self, sizes: &[usize], steps: &[usize]) -> (Vec<usize>, Vec<(Array, Array)>) {
+        let shape = self.shape();
+
+        if sizes.len() > shape.len() {
+            panic!("RANK ERROR");
+        }
+
+        if sizes.len() != steps.len() {
+            panic!("LENGTH ERROR");
+        }
+
+        if steps.contains(&0) {
+            panic!("DOMAIN ERROR");
+        }
+
+        let frame: Vec<usize> = sizes
+            .iter()
+            .zip(steps)
+            .zip(&shape)
+            .map(|((size, step), n)| {
+                let positions = if size % 2 == 0 {
+                    n.saturating_sub(1)
+                } else {
+                    *n
+                };
+                positions.div_ceil(*step)
+            })
+            .collect();
+
+        let mut window_shape = sizes.to_vec();
+        window_shape.extend(&shape[sizes.len()..]);
+
+        let elements = self.elements();
+        let source_strides = strides(&shape);
+        let window_strides = strides(&window_shape);
+        let window_length: usize = window_shape.iter().product();
+
+        let windows = (0..frame.iter().product())
+            .map(|offset| {
+                let starts: Vec<isize> = unravel(offset, &strides(&frame))
+                    .iter()
+                    .zip(sizes.iter().zip(steps))
+                    .map(|(i, (size, step))| (i * step) as isize - ((size - 1) / 2) as isize)
+                    .collect();
+
+                let padding = starts
+                    .iter()
+                    .zip(sizes.iter().zip(&shape))
+                    .map(|(start, (size, n))| {
+                        let end = start + *size as isize;
+                        let pad = if *start < 0 {
+                            -start
+                        } else if end > *n as isize {
+                            *n as isize - end
+                        } else {
+                            0
+                        };
+                        Array::Scalar(Scalar::Integer(pad as i64))
+                    })
+                    .collect();
+
+                let window = (0..window_length)
+                    .map(|offset| {
+                        let index = unravel(offset, &window_strides);
+
+                        let source: Option<Vec<usize>> = index
+                            .iter()
+                            .enumerate()
+                            .map(|(axis, i)| {
+                                let i = *i as isize + starts.get(axis).copied().unwrap_or(0);
+                                usize::try_from(i).ok().filter(|i| *i < shape[axis])
+                            })
+                            .collect();
+
+                        match source {
+                            Some(source) => {
+                                let position: usize =
+                                    source.iter().zip(&source_strides).map(|(i, s)| i * s).sum();
+                                elements[position].clone()
+                            }
+                            None => self.prototype(),
+                        }
+                    })
+                    .collect();
+
+                (padding, Array::from_shape(window_shape.clone(), window))
+            })
+            .collect();
+
+        (frame, windows)
+    }
+}
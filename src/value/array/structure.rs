@@ -0,0 +1,296 @@
+use super::{scalar::Scalar, strides, unravel, Array};
+use crate::value::system;
+
+impl Array {
+    /// Dyadic `↑`: takes the given number of items along each leading axis, from the end when the
+    /// count is negative, padding with the prototype past the edges.
+    pub fn take(&self, counts: &Array) -> Array {
+        if counts.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let counts: Vec<i64> = counts.elements().iter().map(as_index).collect();
+
+        let mut shape = self.shape();
+        if shape.len() < counts.len() {
+            shape = [vec![1; counts.len() - shape.len()], shape].concat();
+        }
+
+        let mut result_shape = shape.clone();
+        for (n, count) in result_shape.iter_mut().zip(&counts) {
+            *n = count.unsigned_abs() as usize;
+        }
+
+        let elements = self.elements();
+        let prototype = self.prototype();
+        let source_strides = strides(&shape);
+        let result_strides = strides(&result_shape);
+
+        let result = (0..result_shape.iter().product())
+            .map(|offset| {
+                let position: Option<usize> = unravel(offset, &result_strides)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(axis, i)| {
+                        let i = match counts.get(axis) {
+                            Some(count) if *count < 0 => i as i64 + shape[axis] as i64 + count,
+                            _ => i as i64,
+                        };
+
+                        usize::try_from(i)
+                            .ok()
+                            .filter(|i| *i < shape[axis])
+                            .map(|i| i * source_strides[axis])
+                    })
+                    .sum();
+
+                match position {
+                    Some(position) => elements[position].clone(),
+                    None => prototype.clone(),
+                }
+            })
+            .collect();
+
+        Array::from_shape(result_shape, result).with_prototype(prototype)
+    }
+
+    /// Dyadic `/`: repeats each item along the last axis as many times as the corresponding count,
+    /// putting that many fill items in its place when the count is negative.
+    pub fn replicate(&self, counts: &Array) -> Array {
+        if counts.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let counts: Vec<i64> = counts.elements().iter().map(as_index).collect();
+        let prototype = self.prototype();
+
+        let mut shape = self.shape();
+        let source = if shape.is_empty() {
+            shape = vec![counts.len()];
+            vec![self.clone(); counts.len()]
+        } else {
+            self.elements()
+        };
+
+        let length = shape[shape.len() - 1];
+        let counts = match counts.len() {
+            1 => vec![counts[0]; length],
+            n if n == length => counts,
+            _ => panic!("LENGTH ERROR"),
+        };
+
+        let elements: Vec<Array> = source
+            .chunks(length.max(1))
+            .flat_map(|row| {
+                row.iter().zip(&counts).flat_map(|(x, c)| {
+                    let item = if *c < 0 { &prototype } else { x };
+                    std::iter::repeat_n(item.clone(), c.unsigned_abs() as usize)
+                })
+            })
+            .collect();
+
+        let last = shape.len() - 1;
+        shape[last] = counts.iter().map(|c| c.unsigned_abs() as usize).sum();
+
+        Array::from_shape(shape, elements).with_prototype(prototype)
+    }
+
+    /// Dyadic `\`: spreads the items along the last axis out according to the counts, repeating
+    /// an item as many times as each positive count and putting fill items in place of zero and
+    /// negative ones.
+    pub fn expand(&self, counts: &Array) -> Array {
+        if counts.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let counts: Vec<i64> = counts.elements().iter().map(as_index).collect();
+        let prototype = self.prototype();
+
+        let mut shape = self.shape();
+        let source = if shape.is_empty() {
+            shape = vec![1];
+            vec![self.clone()]
+        } else {
+            self.elements()
+        };
+
+        let length = shape[shape.len() - 1];
+        let used = counts.iter().filter(|c| **c > 0).count();
+
+        // A single item is used for every positive count.
+        if used != length && length != 1 {
+            panic!("LENGTH ERROR");
+        }
+
+        let rows: usize = shape[..shape.len() - 1].iter().product();
+        let mut elements = vec![];
+
+        for row in 0..rows {
+            let row = &source[row * length..(row + 1) * length];
+            let mut next = 0;
+
+            for count in &counts {
+                if *count > 0 {
+                    elements.extend(std::iter::repeat_n(row[next].clone(), *count as usize));
+                    if length > 1 {
+                        next += 1;
+                    }
+                } else {
+                    let fills = count.unsigned_abs().max(1) as usize;
+                    elements.extend(std::iter::repeat_n(prototype.clone(), fills));
+                }
+            }
+        }
+
+        let last = shape.len() - 1;
+        shape[last] = counts
+            .iter()
+            .map(|c| c.unsigned_abs().max(1) as usize)
+            .sum();
+
+        Array::from_shape(shape, elements).with_prototype(prototype)
+    }
+
+    /// Monadic `⌽`: reverses the order of the items along the last axis.
+    pub fn reverse(&self) -> Array {
+        self.rotate(&Array::Scalar(Scalar::Integer(0)), true)
+    }
+
+    /// Dyadic `⌽`: rotates the items along the last axis to the left by the given amount,
+    /// optionally reversing them first.
+    pub fn rotate(&self, amount: &Array, reverse: bool) -> Array {
+        let amount = match amount {
+            Array::Scalar(s) => s.as_integer(),
+            _ => panic!("RANK ERROR"),
+        };
+
+        let shape = self.shape();
+        let elements = self.elements();
+
+        let Some(&length) = shape.last() else {
+            return self.clone();
+        };
+
+        let rotated = (0..elements.len())
+            .map(|offset| {
+                let row = offset - offset % length.max(1);
+                let mut i = (offset % length.max(1)) as i64;
+                if reverse {
+                    i = length as i64 - 1 - i;
+                }
+                let i = (i + amount).rem_euclid(length.max(1) as i64) as usize;
+
+                elements[row + i].clone()
+            })
+            .collect();
+
+        Array::from_shape(shape, rotated).with_prototype(self.prototype())
+    }
+
+    /// Monadic `⊃`: the first element, or the prototype of an empty array, disclosed.
+    pub fn first(&self) -> Array {
+        match self
+            .elements()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| self.prototype())
+        {
+            Array::Scalar(Scalar::Array(a)) => *a,
+            a => a,
+        }
+    }
+
+    /// Dyadic `⊃`: picks an element, with one index for each level of nesting to go through.
+    pub fn pick_path(&self, path: &Array) -> Array {
+        if path.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        path.elements().iter().fold(self.clone(), |array, index| {
+            let index = index.elements();
+
+            if index.len() != array.rank() {
+                panic!("RANK ERROR");
+            }
+
+            let index: Vec<usize> = index
+                .iter()
+                .zip(array.shape())
+                .map(|(i, n)| to_offset(as_index(i), n))
+                .collect();
+
+            match array.pick(&index) {
+                Array::Scalar(Scalar::Array(a)) => *a.clone(),
+                a => a.clone(),
+            }
+        })
+    }
+
+    /// Dyadic `⌷`: selects along the leading axes with one scalar or vector of indices per axis.
+    pub fn squad(&self, indices: &Array) -> Array {
+        if indices.rank() > 1 {
+            panic!("RANK ERROR");
+        }
+
+        let shape = self.shape();
+        let indices = indices.elements();
+
+        if indices.len() > shape.len() {
+            panic!("RANK ERROR");
+        }
+
+        let mut axes: Vec<Vec<usize>> = vec![];
+        let mut result_shape = vec![];
+
+        for (axis, n) in shape.iter().enumerate() {
+            match indices.get(axis).map(Array::disclosed) {
+                Some(i @ Array::Scalar(_)) => axes.push(vec![to_offset(as_index(i), *n)]),
+                Some(i) => {
+                    if i.rank() > 1 {
+                        panic!("RANK ERROR");
+                    }
+
+                    let i = i.elements();
+                    result_shape.push(i.len());
+                    axes.push(i.iter().map(|i| to_offset(as_index(i), *n)).collect());
+                }
+                None => {
+                    result_shape.push(*n);
+                    axes.push((0..*n).collect());
+                }
+            }
+        }
+
+        let source_strides = strides(&shape);
+        let elements = self.elements();
+
+        let mut selected = vec![0];
+        for (offsets, stride) in axes.iter().zip(source_strides) {
+            selected = selected
+                .iter()
+                .flat_map(|base| offsets.iter().map(move |i| base + i * stride))
+                .collect();
+        }
+
+        Array::from_shape(
+            result_shape,
+            selected.into_iter().map(|i| elements[i].clone()).collect(),
+        )
+        .with_prototype(self.prototype())
+    }
+}
+
+fn as_index(a: &Array) -> i64 {
+    match a {
+        Array::Scalar(s) => s.as_integer(),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// Converts an index counted from `⎕IO` into an offset into an axis of the given length.
+fn to_offset(index: i64, length: usize) -> usize {
+    usize::try_from(index - system::index_origin())
+        .ok()
+        .filter(|i| *i < length)
+        .unwrap_or_else(|| panic!("INDEX ERROR"))
+}
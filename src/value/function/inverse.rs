@@ -0,0 +1,140 @@
+use crate::{
+    primitives::PrimitiveFunction,
+    value::array::{scalar::Scalar, Array},
+};
+
+use super::{monadic_pervasion, power, scalar_pervasion, Function};
+
+impl Function {
+    /// Applies the inverse of the function, as in `f⍣¯1`.
+    ///
+    /// Monadically this undoes `f ⍵`. Dyadically the left argument stays bound, so the result is
+    /// the `x` for which `⍺ f x` gives `omega`.
+    pub fn apply_inverse(&self, alpha: Option<Array>, omega: Array) -> Array {
+        match self {
+            Function::Primitive(p) => primitive_inverse(*p, alpha, omega),
+            Function::Atop(f, g) => g.apply_inverse(alpha, f.apply_inverse(None, omega)),
+            Function::Beside(f, g) => g.apply_inverse(None, f.apply_inverse(alpha, omega)),
+            Function::BindLeft(a, f) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                f.apply_inverse(Some(a.clone()), omega)
+            }
+            Function::BindRight(f, a) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                match f.as_ref() {
+                    Function::Primitive(p) => primitive_left_inverse(*p, a.clone(), omega),
+                    _ => panic!("DOMAIN ERROR"),
+                }
+            }
+            Function::Commute(f) => match (f.as_ref(), alpha) {
+                (Function::Primitive(p), Some(alpha)) => primitive_left_inverse(*p, alpha, omega),
+                _ => panic!("DOMAIN ERROR"),
+            },
+            Function::Power(f, n) => match n {
+                Array::Scalar(n) => power(f, -n.as_integer(), alpha, omega),
+                _ => panic!("RANK ERROR"),
+            },
+            _ => panic!("DOMAIN ERROR"),
+        }
+    }
+}
+
+/// Inverts a primitive monadically, or with respect to its right argument when `alpha` is given.
+fn primitive_inverse(p: PrimitiveFunction, alpha: Option<Array>, omega: Array) -> Array {
+    match (p, alpha) {
+        (PrimitiveFunction::Plus, None) => monadic_pervasion(Scalar::conjugate, omega),
+        (PrimitiveFunction::Plus, Some(alpha)) => scalar_pervasion(|a, b| b - a, alpha, omega),
+        (PrimitiveFunction::Minus, None) => monadic_pervasion(Scalar::negation, omega),
+        (PrimitiveFunction::Minus, Some(alpha)) => scalar_pervasion(|a, b| a - b, alpha, omega),
+        (PrimitiveFunction::Times, Some(alpha)) => scalar_pervasion(|a, b| b / a, alpha, omega),
+        (PrimitiveFunction::Divide, None) => monadic_pervasion(Scalar::reciprocal, omega),
+        (PrimitiveFunction::Divide, Some(alpha)) => scalar_pervasion(|a, b| a / b, alpha, omega),
+        (PrimitiveFunction::Star, None) => monadic_pervasion(Scalar::natural_logarithm, omega),
+        (PrimitiveFunction::Star, Some(alpha)) => {
+            scalar_pervasion(|a, b| b.logarithm(a), alpha, omega)
+        }
+        (PrimitiveFunction::CircleStar, None) => monadic_pervasion(Scalar::exponential, omega),
+        (PrimitiveFunction::CircleStar, Some(alpha)) => {
+            scalar_pervasion(|a, b| a.power(b), alpha, omega)
+        }
+        (PrimitiveFunction::CircleStile, None) => omega.reverse(),
+        (PrimitiveFunction::CircleStile, Some(alpha)) => {
+            omega.rotate(&alpha.pervade(|x| x.negation()), false)
+        }
+        (PrimitiveFunction::LeftTack, None) | (PrimitiveFunction::RightTack, _) => omega,
+        (PrimitiveFunction::Comma, None) => {
+            if omega.rank() != 1 {
+                panic!("DOMAIN ERROR");
+            }
+
+            omega
+        }
+        (PrimitiveFunction::Comma, Some(alpha)) => {
+            let prefix = alpha.elements();
+            let elements = omega.elements();
+
+            if omega.rank() != 1
+                || prefix.len() > elements.len()
+                || Array::Vector(prefix.clone()) != Array::Vector(elements[..prefix.len()].to_vec())
+            {
+                panic!("DOMAIN ERROR");
+            }
+
+            elements[prefix.len()..]
+                .iter()
+                .cloned()
+                .collect::<Array>()
+                .with_prototype(omega.prototype())
+        }
+        (PrimitiveFunction::UpTack, alpha) => {
+            let radix = alpha.unwrap_or(Array::Scalar(Scalar::Integer(2)));
+            Array::encode(&Array::enough_digits(&radix, &omega), &omega)
+        }
+        (PrimitiveFunction::DownTack, alpha) => {
+            let radix = alpha.unwrap_or(Array::Scalar(Scalar::Integer(2)));
+            Array::decode(&radix, &omega)
+        }
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// Inverts a primitive with respect to its left argument, giving the `x` for which `x f right`
+/// is `omega`. This is the inverse of `f∘right`.
+fn primitive_left_inverse(p: PrimitiveFunction, right: Array, omega: Array) -> Array {
+    match p {
+        PrimitiveFunction::Plus => scalar_pervasion(|a, b| a - b, omega, right),
+        PrimitiveFunction::Minus => scalar_pervasion(|a, b| a + b, omega, right),
+        PrimitiveFunction::Times => scalar_pervasion(|a, b| a / b, omega, right),
+        PrimitiveFunction::Divide => scalar_pervasion(|a, b| a * b, omega, right),
+        PrimitiveFunction::Star => scalar_pervasion(|a, b| a.power(b.reciprocal()), omega, right),
+        PrimitiveFunction::CircleStar => {
+            scalar_pervasion(|a, b| b.power(a.reciprocal()), omega, right)
+        }
+        PrimitiveFunction::LeftTack => omega,
+        PrimitiveFunction::Comma => {
+            let suffix = right.elements();
+            let elements = omega.elements();
+
+            if omega.rank() != 1
+                || suffix.len() > elements.len()
+                || Array::Vector(suffix.clone())
+                    != Array::Vector(elements[elements.len() - suffix.len()..].to_vec())
+            {
+                panic!("DOMAIN ERROR");
+            }
+
+            elements[..elements.len() - suffix.len()]
+                .iter()
+                .cloned()
+                .collect::<Array>()
+                .with_prototype(omega.prototype())
+        }
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
@@ -0,0 +1,645 @@
+use std::fmt::Display;
+
+use crate::primitives::PrimitiveFunction;
+
+use super::{
+    array::{scalar::Scalar, Array},
+    system,
+};
+
+mod inverse;
+mod tree;
+mod under;
+
+#[derive(Debug)]
+pub enum Function {
+    Primitive(PrimitiveFunction),
+    /// A system function, named without its quad.
+    System(String),
+    Atop(Box<Function>, Box<Function>),
+    Fork(Box<Function>, Box<Function>, Box<Function>),
+    Key(Box<Function>),
+    Stencil(Box<Function>, Array),
+    Rank(Box<Function>, Array),
+    Beside(Box<Function>, Box<Function>),
+    BindLeft(Array, Box<Function>),
+    BindRight(Box<Function>, Array),
+    Over(Box<Function>, Box<Function>),
+    Behind(Box<Function>, Box<Function>),
+    Power(Box<Function>, Array),
+    PowerUntil(Box<Function>, Box<Function>),
+    Under(Box<Function>, Box<Function>),
+    Commute(Box<Function>),
+    Constant(Array),
+    Reduce(Box<Function>),
+    Scan(Box<Function>),
+}
+
+impl Function {
+    pub fn apply(&self, alpha: Option<Array>, omega: Array) -> Array {
+        match self {
+            Function::Primitive(primitive) => match primitive {
+                PrimitiveFunction::Plus => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| a + b, alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::conjugate, omega)
+                    }
+                }
+                PrimitiveFunction::Minus => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| a - b, alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::negation, omega)
+                    }
+                }
+                PrimitiveFunction::Times => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| a * b, alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::signum, omega)
+                    }
+                }
+                PrimitiveFunction::Divide => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| a / b, alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::reciprocal, omega)
+                    }
+                }
+                PrimitiveFunction::Stile => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| b % a, alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::magnitude, omega)
+                    }
+                }
+                PrimitiveFunction::Circle => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| b.circle(a), alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::pi_times, omega)
+                    }
+                }
+                PrimitiveFunction::LeftTack => {
+                    if let Some(alpha) = alpha {
+                        alpha
+                    } else {
+                        omega
+                    }
+                }
+                PrimitiveFunction::RightTack => omega,
+                PrimitiveFunction::Comma => {
+                    if let Some(alpha) = alpha {
+                        Array::catenate(alpha, omega)
+                    } else {
+                        omega.ravel()
+                    }
+                }
+                PrimitiveFunction::Epsilon => todo!(),
+                PrimitiveFunction::Rho => {
+                    if let Some(alpha) = alpha {
+                        omega.reshape(alpha.as_shape())
+                    } else {
+                        omega
+                            .shape()
+                            .into_iter()
+                            .map(|n| Array::Scalar(Scalar::Integer(n as i64)))
+                            .collect()
+                    }
+                }
+                PrimitiveFunction::Iota => {
+                    if let Some(alpha) = alpha {
+                        Array::index_of(&alpha, &omega)
+                    } else {
+                        omega.index_generator()
+                    }
+                }
+                PrimitiveFunction::Question => {
+                    if let Some(alpha) = alpha {
+                        Array::deal(&alpha, &omega)
+                    } else {
+                        monadic_pervasion(Scalar::roll, omega)
+                    }
+                }
+                PrimitiveFunction::Domino => {
+                    if let Some(alpha) = alpha {
+                        Array::matrix_divide(&alpha, &omega)
+                    } else {
+                        omega.matrix_inverse()
+                    }
+                }
+                PrimitiveFunction::Star => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| a.power(b), alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::exponential, omega)
+                    }
+                }
+                PrimitiveFunction::CircleStar => {
+                    if let Some(alpha) = alpha {
+                        scalar_pervasion(|a, b| b.logarithm(a), alpha, omega)
+                    } else {
+                        monadic_pervasion(Scalar::natural_logarithm, omega)
+                    }
+                }
+                PrimitiveFunction::UpArrow => {
+                    if let Some(alpha) = alpha {
+                        omega.take(&alpha)
+                    } else if system::migration_level() >= 2 {
+                        omega.first()
+                    } else {
+                        mix(omega)
+                    }
+                }
+                PrimitiveFunction::CircleStile => {
+                    if let Some(alpha) = alpha {
+                        omega.rotate(&alpha, false)
+                    } else {
+                        omega.reverse()
+                    }
+                }
+                PrimitiveFunction::RightShoe => {
+                    if let Some(alpha) = alpha {
+                        omega.pick_path(&alpha)
+                    } else if system::migration_level() >= 2 {
+                        mix(omega)
+                    } else {
+                        omega.first()
+                    }
+                }
+                PrimitiveFunction::Squad => {
+                    if let Some(alpha) = alpha {
+                        omega.squad(&alpha)
+                    } else {
+                        omega
+                    }
+                }
+                PrimitiveFunction::UpTack => {
+                    if let Some(alpha) = alpha {
+                        Array::decode(&alpha, &omega)
+                    } else {
+                        Array::decode(&Array::Scalar(Scalar::Integer(2)), &omega)
+                    }
+                }
+                PrimitiveFunction::DownTack => {
+                    if let Some(alpha) = alpha {
+                        Array::encode(&alpha, &omega)
+                    } else {
+                        let radix = Array::Scalar(Scalar::Integer(2));
+                        Array::encode(&Array::enough_digits(&radix, &omega), &omega)
+                    }
+                }
+                PrimitiveFunction::EqualUnderbar => {
+                    if let Some(alpha) = alpha {
+                        Array::Scalar(Scalar::Integer((alpha == omega) as i64))
+                    } else {
+                        Array::Scalar(Scalar::Integer(omega.depth()))
+                    }
+                }
+                PrimitiveFunction::IotaUnderbar => {
+                    if let Some(alpha) = alpha {
+                        Array::interval_index(&alpha, &omega)
+                    } else {
+                        omega.where_indices()
+                    }
+                }
+                PrimitiveFunction::Slash => {
+                    if let Some(alpha) = alpha {
+                        omega.replicate(&alpha)
+                    } else {
+                        panic!("VALENCE ERROR")
+                    }
+                }
+                PrimitiveFunction::Backslash => {
+                    if let Some(alpha) = alpha {
+                        omega.expand(&alpha)
+                    } else {
+                        panic!("VALENCE ERROR")
+                    }
+                }
+                PrimitiveFunction::EpsilonUnderbar => {
+                    if let Some(alpha) = alpha {
+                        Array::find(&alpha, &omega)
+                    } else {
+                        panic!("VALENCE ERROR")
+                    }
+                }
+                PrimitiveFunction::DownTackJot => {
+                    if let Some(alpha) = alpha {
+                        omega.format_by(&alpha)
+                    } else {
+                        omega.format()
+                    }
+                }
+            },
+            Function::System(name) => system::call(name, alpha, omega),
+            Function::Atop(f, g) => f.apply(None, g.apply(alpha, omega)),
+            Function::Fork(f, g, h) => g.apply(
+                Some(f.apply(alpha.clone(), omega.clone())),
+                h.apply(alpha, omega),
+            ),
+            Function::Key(f) => key(f, alpha, omega),
+            Function::Stencil(f, spec) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                stencil(f, spec, omega)
+            }
+            Function::Rank(f, spec) => rank(f, spec, alpha, omega),
+            Function::Beside(f, g) => f.apply(alpha, g.apply(None, omega)),
+            Function::BindLeft(a, f) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                f.apply(Some(a.clone()), omega)
+            }
+            Function::BindRight(f, a) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                f.apply(Some(omega), a.clone())
+            }
+            Function::Over(f, g) => f.apply(alpha.map(|a| g.apply(None, a)), g.apply(None, omega)),
+            Function::Behind(f, g) => {
+                let alpha = f.apply(None, alpha.unwrap_or_else(|| omega.clone()));
+                g.apply(Some(alpha), omega)
+            }
+            Function::Power(f, n) => match n {
+                Array::Scalar(n) => power(f, n.as_integer(), alpha, omega),
+                _ => panic!("RANK ERROR"),
+            },
+            Function::PowerUntil(f, g) => {
+                let mut omega = omega;
+
+                loop {
+                    let next = f.apply(alpha.clone(), omega.clone());
+
+                    let done = match g.apply(Some(next.clone()), omega) {
+                        Array::Scalar(s) => s.as_integer(),
+                        _ => panic!("DOMAIN ERROR"),
+                    };
+
+                    match done {
+                        1 => return next,
+                        0 => omega = next,
+                        _ => panic!("DOMAIN ERROR"),
+                    }
+                }
+            }
+            Function::Under(f, g) => under::under(f, g, alpha, omega),
+            Function::Commute(f) => match alpha {
+                Some(alpha) => f.apply(Some(omega), alpha),
+                None => f.apply(Some(omega.clone()), omega),
+            },
+            Function::Constant(a) => a.clone(),
+            Function::Reduce(f) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                reduce(f, omega)
+            }
+            Function::Scan(f) => {
+                if alpha.is_some() {
+                    panic!("VALENCE ERROR");
+                }
+
+                scan(f, omega)
+            }
+        }
+    }
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Function::Primitive(p) => write!(f, "{}", p),
+            Function::System(name) => write!(f, "⎕{}", name),
+            Function::Atop(a, b) => write!(f, "{}{}", a.tine(), b.last_tine()),
+            Function::Fork(a, b, c) => match a.as_ref() {
+                Function::Constant(a) => write!(f, "{}{}{}", a.literal(), b.tine(), c.last_tine()),
+                a => write!(f, "{}{}{}", a.tine(), b.tine(), c.last_tine()),
+            },
+            Function::Key(a) => write!(f, "{}⌸", a.left_operand()),
+            Function::Stencil(a, b) => write!(f, "{}⌺{}", a.left_operand(), b.literal()),
+            Function::Rank(a, b) => write!(f, "{}⍤{}", a.left_operand(), b.literal()),
+            Function::Beside(a, b) => write!(f, "{}∘{}", a.left_operand(), b.right_operand()),
+            Function::BindLeft(a, b) => write!(f, "{}∘{}", a.literal(), b.right_operand()),
+            Function::BindRight(a, b) => write!(f, "{}∘{}", a.left_operand(), b.literal()),
+            Function::Over(a, b) => write!(f, "{}⍥{}", a.left_operand(), b.right_operand()),
+            Function::Behind(a, b) => write!(f, "{}⍛{}", a.left_operand(), b.right_operand()),
+            Function::Power(a, b) => write!(f, "{}⍣{}", a.left_operand(), b.literal()),
+            Function::PowerUntil(a, b) => {
+                write!(f, "{}⍣{}", a.left_operand(), b.right_operand())
+            }
+            Function::Under(a, b) => write!(f, "{}⍢{}", a.left_operand(), b.right_operand()),
+            Function::Commute(a) => write!(f, "{}⍨", a.left_operand()),
+            Function::Constant(a) => write!(f, "{}⍨", a.literal()),
+            Function::Reduce(a) => write!(f, "{}/", a.left_operand()),
+            Function::Scan(a) => write!(f, "{}\\", a.left_operand()),
+        }
+    }
+}
+
+impl Function {
+    /// Displays the function as the left operand of an operator, parenthesising trains.
+    fn left_operand(&self) -> String {
+        match self {
+            Function::Atop(_, _) | Function::Fork(_, _, _) => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Displays the function as a tine of a train other than the last, parenthesising trains so
+    /// that they are not absorbed into the surrounding one.
+    fn tine(&self) -> String {
+        self.left_operand()
+    }
+
+    /// Displays the function as the last tine of a train. A fork continues the train without
+    /// parentheses, but an atop there would be read as part of a longer train.
+    fn last_tine(&self) -> String {
+        match self {
+            Function::Atop(_, _) => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Displays the function as the right operand of an operator, which binds only a single
+    /// primitive without parentheses.
+    fn right_operand(&self) -> String {
+        match self {
+            Function::Primitive(_) => self.to_string(),
+            _ => format!("({})", self),
+        }
+    }
+}
+
+/// Monadic `↑`, or `⊃` from `⎕ML` 2: combines the items into an array of higher rank.
+fn mix(omega: Array) -> Array {
+    if omega.rank() == 0 {
+        omega.first()
+    } else {
+        assemble(omega.shape(), omega.elements())
+    }
+}
+
+/// Applies a monadic scalar function to every scalar of the array.
+fn monadic_pervasion<F>(scalar_fn: F, omega: Array) -> Array
+where
+    F: Clone + Fn(&Scalar) -> Scalar,
+{
+    omega.pervade(|s| scalar_fn(&s).finite())
+}
+
+fn scalar_pervasion<F>(scalar_fn: F, alpha: Array, omega: Array) -> Array
+where
+    F: Clone + Fn(Scalar, Scalar) -> Scalar,
+{
+    match (alpha, omega) {
+        (Array::Scalar(a), Array::Scalar(b)) => Array::Scalar(scalar_fn(a, b).finite()),
+        (Array::Scalar(a), omega) => omega.pervade(|s| scalar_fn(a.clone(), s).finite()),
+        (alpha, Array::Scalar(b)) => alpha.pervade(|s| scalar_fn(s, b.clone()).finite()),
+        (alpha, omega) => {
+            if alpha.rank() != omega.rank() {
+                panic!("RANK ERROR");
+            }
+
+            if alpha.shape() != omega.shape() {
+                panic!("LENGTH ERROR");
+            }
+
+            let elements = alpha
+                .elements()
+                .into_iter()
+                .zip(omega.elements())
+                .map(|(a, b)| scalar_pervasion(scalar_fn.clone(), a, b))
+                .collect();
+
+            Array::from_shape(alpha.shape(), elements)
+        }
+    }
+}
+
+/// `/` as an operator: inserts `f` between the items along the last axis, evaluating from the
+/// right. Reducing no items gives the identity element of `f`.
+fn reduce(f: &Function, omega: Array) -> Array {
+    if omega.rank() == 0 {
+        return omega;
+    }
+
+    let (frame, rows) = omega.cells(1);
+
+    let results = rows
+        .into_iter()
+        .map(|row| {
+            let mut items = row.major_cells().into_iter().rev();
+
+            match items.next() {
+                Some(last) => items.fold(last, |acc, item| f.apply(Some(item), acc)),
+                None => Array::Scalar(identity(f)),
+            }
+        })
+        .collect();
+
+    Array::from_shape(frame, results)
+}
+
+/// `\` as an operator: reduces each prefix of the items along the last axis.
+fn scan(f: &Function, omega: Array) -> Array {
+    if omega.rank() == 0 {
+        return omega;
+    }
+
+    let (frame, rows) = omega.cells(1);
+    let prototype = omega.prototype();
+
+    let results = rows
+        .into_iter()
+        .map(|row| {
+            let items = row.major_cells();
+
+            (1..=items.len())
+                .map(|n| {
+                    let mut prefix = items[..n].iter().rev().cloned();
+                    let last = prefix.next().unwrap();
+                    prefix.fold(last, |acc, item| f.apply(Some(item), acc))
+                })
+                .collect::<Array>()
+                .with_prototype(prototype.clone())
+        })
+        .collect();
+
+    assemble(frame, results)
+}
+
+/// The value `x` for which `x f y` is `y`, which is what reducing no items with `f` gives.
+fn identity(f: &Function) -> Scalar {
+    match f {
+        Function::Primitive(
+            PrimitiveFunction::Plus | PrimitiveFunction::Minus | PrimitiveFunction::Stile,
+        ) => Scalar::Integer(0),
+        Function::Primitive(
+            PrimitiveFunction::Times | PrimitiveFunction::Divide | PrimitiveFunction::Star,
+        ) => Scalar::Integer(1),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// `⍣` with an array right operand: applies `f` the given number of times, or its inverse if
+/// the number is negative. A left argument stays bound to every application.
+fn power(f: &Function, n: i64, alpha: Option<Array>, omega: Array) -> Array {
+    (0..n.unsigned_abs()).fold(omega, |omega, _| {
+        if n < 0 {
+            f.apply_inverse(alpha.clone(), omega)
+        } else {
+            f.apply(alpha.clone(), omega)
+        }
+    })
+}
+
+/// `⌸`: groups the major cells of `omega` (or the indices of `omega` when monadic) by the unique
+/// major cells of the keys, and applies `f` to each key and its group.
+fn key(f: &Function, alpha: Option<Array>, omega: Array) -> Array {
+    let (keys, values) = match alpha {
+        Some(alpha) => (alpha.major_cells(), omega.major_cells()),
+        None => {
+            let keys = omega.major_cells();
+            let origin = system::index_origin();
+            let indices = (0..keys.len())
+                .map(|i| Array::Scalar(Scalar::Integer(i as i64 + origin)))
+                .collect();
+            (keys, indices)
+        }
+    };
+
+    if keys.len() != values.len() {
+        panic!("LENGTH ERROR");
+    }
+
+    let mut groups: Vec<(Array, Vec<Array>)> = vec![];
+
+    for (key, value) in keys.into_iter().zip(values) {
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(value),
+            None => groups.push((key, vec![value])),
+        }
+    }
+
+    Array::mix(
+        groups
+            .into_iter()
+            .map(|(key, group)| f.apply(Some(key), Array::mix(group)))
+            .collect(),
+    )
+}
+
+/// `⌺`: applies `f` to each moving window of `omega`, with the padding of the window as the left
+/// argument.
+///
+/// The right operand holds the window sizes, or the sizes and steps as the rows of a matrix.
+fn stencil(f: &Function, spec: &Array, omega: Array) -> Array {
+    let (sizes, steps) = if spec.rank() == 2 {
+        let rows = spec.major_cells();
+        if rows.len() != 2 {
+            panic!("LENGTH ERROR");
+        }
+        (rows[0].as_shape(), rows[1].as_shape())
+    } else {
+        let sizes = spec.as_shape();
+        let steps = vec![1; sizes.len()];
+        (sizes, steps)
+    };
+
+    let (frame, windows) = omega.windows(&sizes, &steps);
+
+    assemble(
+        frame,
+        windows
+            .into_iter()
+            .map(|(padding, window)| f.apply(Some(padding), window))
+            .collect(),
+    )
+}
+
+/// `⍤` with an array right operand: applies `f` to the cells of the ranks given by `spec`.
+///
+/// `spec` holds the monadic, left and right ranks, or fewer numbers which are extended as
+/// `r` → `r r r` and `l r` → `r l r`. Negative ranks count down from the rank of the argument.
+fn rank(f: &Function, spec: &Array, alpha: Option<Array>, omega: Array) -> Array {
+    if spec.rank() > 1 {
+        panic!("RANK ERROR");
+    }
+
+    let spec: Vec<i64> = spec
+        .elements()
+        .iter()
+        .map(|a| match a {
+            Array::Scalar(s) => s.as_integer(),
+            _ => panic!("DOMAIN ERROR"),
+        })
+        .collect();
+
+    let (monadic, left, right) = match spec[..] {
+        [r] => (r, r, r),
+        [l, r] => (r, l, r),
+        [m, l, r] => (m, l, r),
+        _ => panic!("LENGTH ERROR"),
+    };
+
+    let cell_rank = |k: i64, array: &Array| {
+        let rank = array.rank() as i64;
+        (if k < 0 { rank + k } else { k }).clamp(0, rank) as usize
+    };
+
+    match alpha {
+        None => {
+            let (frame, cells) = omega.cells(cell_rank(monadic, &omega));
+
+            assemble(
+                frame,
+                cells.into_iter().map(|cell| f.apply(None, cell)).collect(),
+            )
+        }
+        Some(alpha) => {
+            let (left_frame, left_cells) = alpha.cells(cell_rank(left, &alpha));
+            let (right_frame, right_cells) = omega.cells(cell_rank(right, &omega));
+
+            let frame = if left_frame.is_empty() {
+                right_frame
+            } else if right_frame.is_empty() || left_frame == right_frame {
+                left_frame
+            } else if left_frame.len() != right_frame.len() {
+                panic!("RANK ERROR")
+            } else {
+                panic!("LENGTH ERROR")
+            };
+
+            let count = frame.iter().product();
+            let left_cells = left_cells.into_iter().cycle().take(count);
+            let right_cells = right_cells.into_iter().cycle().take(count);
+
+            assemble(
+                frame,
+                left_cells
+                    .zip(right_cells)
+                    .map(|(l, r)| f.apply(Some(l), r))
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Mixes the results of applying a function to cells back into an array with the given frame.
+fn assemble(frame: Vec<usize>, results: Vec<Array>) -> Array {
+    let results = Array::mix(results);
+
+    let mut shape = frame;
+    shape.extend(results.shape().into_iter().skip(1));
+
+    results.reshape(shape)
+}
@@ -0,0 +1,154 @@
+use super::{Array, Function};
+
+/// A rectangle of text with the column that connectors from a parent should attach to.
+struct Block {
+    lines: Vec<Vec<char>>,
+    width: usize,
+    anchor: usize,
+}
+
+impl Block {
+    fn leaf(text: &str) -> Block {
+        let line: Vec<char> = text.chars().collect();
+        let width = line.len();
+
+        Block {
+            lines: vec![line],
+            width,
+            anchor: width.saturating_sub(1) / 2,
+        }
+    }
+
+    /// Lays the blocks out side by side, one column apart, returning the combined lines and the
+    /// anchor of each block within them.
+    fn beside(blocks: Vec<Block>) -> (Vec<Vec<char>>, Vec<usize>) {
+        let height = blocks.iter().map(|b| b.lines.len()).max().unwrap_or(0);
+        let mut lines = vec![vec![]; height];
+        let mut anchors = vec![];
+
+        for (i, block) in blocks.into_iter().enumerate() {
+            if i > 0 {
+                lines.iter_mut().for_each(|line| line.push(' '));
+            }
+            anchors.push(lines[0].len() + block.anchor);
+
+            for (row, line) in lines.iter_mut().enumerate() {
+                match block.lines.get(row) {
+                    Some(l) => line.extend(l),
+                    None => line.extend(std::iter::repeat_n(' ', block.width)),
+                }
+            }
+        }
+
+        (lines, anchors)
+    }
+
+    /// Joins the children under a branch running from the first anchor to the last, with `joint`
+    /// at `at`, which becomes the anchor of the result.
+    fn branch(children: Vec<Block>, joint: char, at: impl Fn(&[usize]) -> usize) -> Block {
+        let (lines, anchors) = Block::beside(children);
+        let width = lines[0].len();
+        let (first, last) = (anchors[0], anchors[anchors.len() - 1]);
+        let anchor = at(&anchors);
+
+        let mut branch = vec![' '; width.max(anchor + 1)];
+        for (column, c) in branch
+            .iter_mut()
+            .enumerate()
+            .take(last.max(anchor) + 1)
+            .skip(first)
+        {
+            *c = if column == anchor {
+                joint
+            } else if column == first {
+                '┌'
+            } else if column == last {
+                '┐'
+            } else if anchors.contains(&column) {
+                '┬'
+            } else {
+                '─'
+            };
+        }
+
+        let width = branch.len();
+        let mut all = vec![branch];
+        all.extend(lines.into_iter().map(|mut line| {
+            line.resize(width, ' ');
+            line
+        }));
+
+        Block {
+            lines: all,
+            width,
+            anchor,
+        }
+    }
+
+    /// Hangs the operands of an operator below its symbol.
+    fn operator(symbol: char, operands: Vec<Block>) -> Block {
+        let block = match operands.len() {
+            1 => Block::branch(operands, '┘', |anchors| anchors[0] + 1),
+            _ => Block::branch(operands, '┴', |anchors| {
+                (anchors[0] + anchors[1]).div_ceil(2)
+            }),
+        };
+
+        let mut label = vec![' '; block.width];
+        label[block.anchor] = symbol;
+
+        let mut lines = vec![label];
+        lines.extend(block.lines);
+
+        Block { lines, ..block }
+    }
+}
+
+impl Function {
+    /// Renders the function as a tree, with the tines of each train hanging from a branch and the
+    /// operands of each operator hanging below its symbol.
+    pub fn tree(&self) -> String {
+        self.block()
+            .lines
+            .iter()
+            .map(|line| line.iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn block(&self) -> Block {
+        let array = |a: &Array| Block::leaf(&a.literal());
+
+        match self {
+            Function::Primitive(p) => Block::leaf(&p.to_string()),
+            Function::System(name) => Block::leaf(&format!("⎕{}", name)),
+            Function::Atop(a, b) => Block::branch(vec![a.block(), b.block()], '┴', |anchors| {
+                (anchors[0] + anchors[1]).div_ceil(2)
+            }),
+            Function::Fork(a, b, c) => {
+                let left = match a.as_ref() {
+                    Function::Constant(a) => array(a),
+                    a => a.block(),
+                };
+                Block::branch(vec![left, b.block(), c.block()], '┼', |anchors| {
+                    anchors[1]
+                })
+            }
+            Function::Key(a) => Block::operator('⌸', vec![a.block()]),
+            Function::Stencil(a, b) => Block::operator('⌺', vec![a.block(), array(b)]),
+            Function::Rank(a, b) => Block::operator('⍤', vec![a.block(), array(b)]),
+            Function::Beside(a, b) => Block::operator('∘', vec![a.block(), b.block()]),
+            Function::BindLeft(a, b) => Block::operator('∘', vec![array(a), b.block()]),
+            Function::BindRight(a, b) => Block::operator('∘', vec![a.block(), array(b)]),
+            Function::Over(a, b) => Block::operator('⍥', vec![a.block(), b.block()]),
+            Function::Behind(a, b) => Block::operator('⍛', vec![a.block(), b.block()]),
+            Function::Power(a, b) => Block::operator('⍣', vec![a.block(), array(b)]),
+            Function::PowerUntil(a, b) => Block::operator('⍣', vec![a.block(), b.block()]),
+            Function::Under(a, b) => Block::operator('⍢', vec![a.block(), b.block()]),
+            Function::Commute(a) => Block::operator('⍨', vec![a.block()]),
+            Function::Constant(a) => Block::operator('⍨', vec![array(a)]),
+            Function::Reduce(a) => Block::operator('/', vec![a.block()]),
+            Function::Scan(a) => Block::operator('\\', vec![a.block()]),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    primitives::PrimitiveFunction,
+    value::array::{scalar::Scalar, Array},
+};
+
+use super::Function;
+
+/// `⍢`: applies `g`, then `f`, then undoes `g`.
+///
+/// When `g` only selects or rearranges elements, the result of `f` is put back in the places `g`
+/// took its argument from, leaving the rest of `omega` untouched. Otherwise `g` is undone by its
+/// inverse. Dyadically, `f` is applied between `g` of both arguments.
+pub fn under(f: &Function, g: &Function, alpha: Option<Array>, omega: Array) -> Array {
+    let alpha = alpha.map(|a| g.apply(None, a));
+    let changed = f.apply(alpha, g.apply(None, omega.clone()));
+
+    if !g.is_structural() {
+        return g.apply_inverse(None, changed);
+    }
+
+    let shape = omega.shape();
+    let positions = (1..=shape.iter().product::<usize>())
+        .map(|i| Array::Scalar(Scalar::Integer(i as i64)))
+        .collect();
+    let selected = g.apply(None, Array::from_shape(shape.clone(), positions));
+
+    let replacements = if selected.rank() == 0 {
+        vec![changed]
+    } else if selected.shape() == changed.shape() {
+        changed.elements()
+    } else {
+        panic!("LENGTH ERROR")
+    };
+
+    let mut elements = omega.elements();
+
+    for (position, replacement) in selected.elements().iter().zip(replacements) {
+        let position = match position {
+            Array::Scalar(s) => usize::try_from(s.as_integer()).ok(),
+            _ => None,
+        };
+
+        match position {
+            // Positions padded in by an overtake have nowhere to go back to.
+            Some(0) => (),
+            Some(i) if i <= elements.len() => elements[i - 1] = replacement,
+            _ => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    Array::from_shape(shape, elements)
+}
+
+impl Function {
+    /// Whether the function only selects and rearranges the elements of its right argument, so
+    /// that applying it to the positions of the elements tells where each result came from.
+    fn is_structural(&self) -> bool {
+        match self {
+            Function::Primitive(p) => matches!(
+                p,
+                PrimitiveFunction::LeftTack
+                    | PrimitiveFunction::RightTack
+                    | PrimitiveFunction::Comma
+                    | PrimitiveFunction::UpArrow
+                    | PrimitiveFunction::CircleStile
+                    | PrimitiveFunction::RightShoe
+                    | PrimitiveFunction::Squad
+            ),
+            // Bound to a left argument, only functions that select from their right argument
+            // still just rearrange it, unlike `5∘,` or `A∘⊣`.
+            Function::BindLeft(_, f) => matches!(
+                **f,
+                Function::Primitive(
+                    PrimitiveFunction::UpArrow
+                        | PrimitiveFunction::CircleStile
+                        | PrimitiveFunction::Squad
+                        | PrimitiveFunction::RightShoe
+                        | PrimitiveFunction::Rho
+                )
+            ),
+            Function::Atop(f, g) | Function::Beside(f, g) => f.is_structural() && g.is_structural(),
+            _ => false,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use crate::eval_tree::EvalTree;
+use crate::{
+    eval_tree::EvalTree,
+    primitives::{PrimitiveDyadicOperator, PrimitiveFunction, PrimitiveMonadicOperator},
+};
 
 use self::{
     array::{scalar::Scalar, Array},
@@ -9,6 +12,7 @@ use self::{
 
 pub mod array;
 pub mod function;
+pub mod system;
 
 #[derive(Debug)]
 pub enum Value {
@@ -55,7 +59,8 @@ pub fn eval(tree: &EvalTree) -> Value {
                 })
                 .collect(),
         ),
-        EvalTree::Function(_) => todo!(),
+        EvalTree::Function(f) => eval(f),
+        EvalTree::Isolated(a) => eval(a),
         EvalTree::MonadicFunctionApplication(f, r) => {
             Value::Array(if let Value::Function(f) = eval(f.as_ref()) {
                 f.apply(None, eval(r.as_ref()).as_array())
@@ -73,22 +78,111 @@ pub fn eval(tree: &EvalTree) -> Value {
                 panic!("wtf")
             })
         }
-        EvalTree::BoundLeftArgument(_, _) => todo!(),
-        EvalTree::MonadicOperatorApplication(_, _) => todo!(),
-        EvalTree::BoundRightOperand(_, _) => todo!(),
-        EvalTree::DyadicOperatorApplication(_, _, _) => todo!(),
+        EvalTree::BoundLeftArgument(_, _) => panic!("SYNTAX ERROR"),
+        EvalTree::MonadicOperatorApplication(l, o) => {
+            Value::Function(if let EvalTree::PrimitiveMonadicOperator(o) = o.as_ref() {
+                match o {
+                    PrimitiveMonadicOperator::Commute => match eval(l) {
+                        Value::Function(f) => Function::Commute(Box::new(f)),
+                        Value::Array(a) => Function::Constant(a),
+                    },
+                    PrimitiveMonadicOperator::Key => Function::Key(Box::new(eval(l).as_function())),
+                    PrimitiveMonadicOperator::Slash => {
+                        Function::Reduce(Box::new(eval(l).as_function()))
+                    }
+                    PrimitiveMonadicOperator::Backslash => {
+                        Function::Scan(Box::new(eval(l).as_function()))
+                    }
+                }
+            } else {
+                unreachable!()
+            })
+        }
+        EvalTree::BoundRightOperand(_, _) => panic!("SYNTAX ERROR"),
+        EvalTree::DyadicOperatorApplication(l, o, r) => {
+            Value::Function(if let EvalTree::PrimitiveDyadicOperator(o) = o.as_ref() {
+                match o {
+                    PrimitiveDyadicOperator::Atop => match eval(r) {
+                        Value::Array(k) => Function::Rank(Box::new(eval(l).as_function()), k),
+                        Value::Function(g) => {
+                            Function::Atop(Box::new(eval(l).as_function()), Box::new(g))
+                        }
+                    },
+                    PrimitiveDyadicOperator::Stencil => {
+                        Function::Stencil(Box::new(eval(l).as_function()), eval(r).as_array())
+                    }
+                    PrimitiveDyadicOperator::Jot => match (eval(l), eval(r)) {
+                        (Value::Function(f), Value::Function(g)) => {
+                            Function::Beside(Box::new(f), Box::new(g))
+                        }
+                        (Value::Array(a), Value::Function(f)) => Function::BindLeft(a, Box::new(f)),
+                        (Value::Function(f), Value::Array(a)) => {
+                            Function::BindRight(Box::new(f), a)
+                        }
+                        (Value::Array(_), Value::Array(_)) => panic!("SYNTAX ERROR"),
+                    },
+                    PrimitiveDyadicOperator::Over => Function::Over(
+                        Box::new(eval(l).as_function()),
+                        Box::new(eval(r).as_function()),
+                    ),
+                    PrimitiveDyadicOperator::Behind => Function::Behind(
+                        Box::new(eval(l).as_function()),
+                        Box::new(eval(r).as_function()),
+                    ),
+                    PrimitiveDyadicOperator::Under => Function::Under(
+                        Box::new(eval(l).as_function()),
+                        Box::new(eval(r).as_function()),
+                    ),
+                    PrimitiveDyadicOperator::Power => match eval(r) {
+                        Value::Array(n) => Function::Power(Box::new(eval(l).as_function()), n),
+                        Value::Function(g) => {
+                            Function::PowerUntil(Box::new(eval(l).as_function()), Box::new(g))
+                        }
+                    },
+                }
+            } else {
+                unreachable!()
+            })
+        }
         EvalTree::Atop(f, g) => Value::Function(Function::Atop(
             Box::new(eval(f).as_function()),
             Box::new(eval(g).as_function()),
         )),
         EvalTree::Fork(f, g, h) => Value::Function(Function::Fork(
-            Box::new(eval(f).as_function()),
+            Box::new(match eval(f) {
+                Value::Array(a) => Function::Constant(a),
+                Value::Function(f) => f,
+            }),
             Box::new(eval(g).as_function()),
             Box::new(eval(h).as_function()),
         )),
-        EvalTree::Integer(i) => Value::Array(Array::Scalar(Scalar::Integer(*i))),
-        EvalTree::Float(f) => Value::Array(Array::Scalar(Scalar::Float(*f))),
+        EvalTree::Number(n) => Value::Array(Array::Scalar(Scalar::from_literal(n))),
+        EvalTree::SystemName(name) if system::is_function(name) => {
+            Value::Function(Function::System(name.clone()))
+        }
+        EvalTree::SystemName(name) => Value::Array(system::get(name)),
+        EvalTree::Empty(prototype) => {
+            Value::Array(Array::Empty(vec![0], Box::new(eval(prototype).as_array())))
+        }
+        EvalTree::Assignment(name, value) => {
+            let value = eval(value).as_array();
+
+            match name.as_ref() {
+                EvalTree::SystemName(name) => system::set(name, &value),
+                _ => panic!("SYNTAX ERROR"),
+            }
+
+            Value::Array(value)
+        }
+        EvalTree::LeftArrow => panic!("SYNTAX ERROR"),
+        EvalTree::Character(c) => Value::Array(Array::Scalar(Scalar::Character(*c))),
         EvalTree::PrimitiveFunction(p) => Value::Function(Function::Primitive(*p)),
+        EvalTree::PrimitiveMonadicOperator(PrimitiveMonadicOperator::Slash) => {
+            Value::Function(Function::Primitive(PrimitiveFunction::Slash))
+        }
+        EvalTree::PrimitiveMonadicOperator(PrimitiveMonadicOperator::Backslash) => {
+            Value::Function(Function::Primitive(PrimitiveFunction::Backslash))
+        }
         EvalTree::PrimitiveMonadicOperator(_) => panic!("SYNTAX ERROR"),
         EvalTree::PrimitiveDyadicOperator(_) => panic!("SYNTAX ERROR"),
         EvalTree::Unfinished(_) => unreachable!(),
@@ -0,0 +1,264 @@
+use std::fs;
+
+use ::csv::{QuoteStyle, ReaderBuilder, WriterBuilder};
+
+use super::{characters, files::fail, is_text, real, text};
+use crate::value::array::{scalar::Scalar, Array};
+
+/// What follows the source or destination in the right argument of `⎕CSV`.
+struct Options {
+    /// `''` for a file name, `'S'` for a character vector or `'L'` for a vector of lines.
+    description: String,
+    /// How to convert each column, either one for all of them or one for each.
+    types: Array,
+    header: bool,
+    separator: u8,
+    quote: Option<u8>,
+    /// Whether the data is a vector of columns rather than a matrix.
+    columns: bool,
+}
+
+impl Options {
+    /// The type of the column, which is 1 when the types stop before it.
+    fn column_type(&self, column: usize) -> i64 {
+        match &self.types {
+            Array::Scalar(t) => t.as_integer(),
+            types => types.elements().get(column).map_or(1, integer),
+        }
+    }
+}
+
+/// `⎕CSV`: reads comma-separated values given alone, or writes the data on the left as them. The
+/// right argument is the source or destination, optionally followed in order by:
+///
+/// - What it is: `''` for the name of a file, `'S'` for a character vector of text or `'L'` for
+///   a vector of lines. Writing to a file overwrites it and gives the number of bytes written.
+/// - The type of each column when reading, or of them all: 0 to leave it out, 1 for text, 2 for a
+///   number where the field is one and text otherwise, 3 for numbers only, or 4 for numbers if
+///   every field in the column is one and text otherwise. The default is 1.
+/// - 1 if there is a header row, which is then given or taken as a second item after the data.
+/// - The separator, which is `,` by default.
+/// - The quote, which is `"` by default, or `''` to not quote fields at all.
+/// - 1 for the data to be a vector of columns rather than a matrix.
+pub fn csv(alpha: Option<&Array>, omega: &Array) -> Array {
+    let items = if is_text(omega) {
+        vec![omega.clone()]
+    } else {
+        omega.elements()
+    };
+
+    if items.is_empty() || items.len() > 7 {
+        panic!("LENGTH ERROR");
+    }
+
+    let flag = |i| items.get(i).map(integer).unwrap_or(0) == 1;
+    let options = Options {
+        description: items.get(1).map_or(String::new(), text),
+        types: items
+            .get(2)
+            .cloned()
+            .unwrap_or(Array::Scalar(Scalar::Integer(1))),
+        header: flag(3),
+        separator: items.get(4).map_or(b',', byte),
+        quote: match items.get(5) {
+            Some(quote) if text(quote).is_empty() => None,
+            Some(quote) => Some(byte(quote)),
+            None => Some(b'"'),
+        },
+        columns: flag(6),
+    };
+
+    match alpha {
+        Some(data) => write(data, &items[0], &options),
+        None => read(&items[0], &options),
+    }
+}
+
+fn read(source: &Array, options: &Options) -> Array {
+    let content = match options.description.as_str() {
+        "" => fs::read_to_string(text(source)).unwrap_or_else(fail),
+        "S" => text(source),
+        "L" => source.elements().iter().map(|l| text(l) + "\n").collect(),
+        _ => panic!("DOMAIN ERROR"),
+    };
+
+    let mut reader = ReaderBuilder::new();
+    reader
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(options.separator);
+    match options.quote {
+        Some(quote) => reader.quote(quote),
+        None => reader.quoting(false),
+    };
+
+    let mut records: Vec<Vec<String>> = reader
+        .from_reader(content.as_bytes())
+        .records()
+        .map(|record| {
+            let record = record.unwrap_or_else(|e| panic!("DOMAIN ERROR: invalid CSV: {}", e));
+            record.iter().map(String::from).collect()
+        })
+        .collect();
+
+    let mut header = if options.header && !records.is_empty() {
+        records.remove(0)
+    } else {
+        vec![]
+    };
+
+    // Short rows are padded with empty fields.
+    let width = records.iter().chain([&header]).map(Vec::len).max();
+    let width = width.unwrap_or(0);
+    for record in records.iter_mut().chain([&mut header]) {
+        record.resize(width, String::new());
+    }
+
+    let kept: Vec<usize> = (0..width)
+        .filter(|c| options.column_type(*c) != 0)
+        .collect();
+
+    let columns: Vec<Vec<Array>> = kept
+        .iter()
+        .map(|c| {
+            let fields = records.iter().map(|r| r[*c].as_str());
+            match options.column_type(*c) {
+                1 => fields.map(characters).collect(),
+                2 => fields
+                    .map(|f| number(f).map_or_else(|| characters(f), Array::Scalar))
+                    .collect(),
+                3 => fields
+                    .map(|f| Array::Scalar(number(f).unwrap_or_else(|| panic!("DOMAIN ERROR"))))
+                    .collect(),
+                4 => match fields.clone().map(number).collect::<Option<Vec<_>>>() {
+                    Some(numbers) => numbers.into_iter().map(Array::Scalar).collect(),
+                    None => fields.map(characters).collect(),
+                },
+                _ => panic!("DOMAIN ERROR"),
+            }
+        })
+        .collect();
+
+    let data = if options.columns {
+        columns.into_iter().map(Array::from_iter).collect()
+    } else {
+        let elements = (0..records.len())
+            .flat_map(|r| columns.iter().map(move |c| c[r].clone()))
+            .collect();
+        Array::from_shape(vec![records.len(), kept.len()], elements).with_prototype(characters(""))
+    };
+
+    if options.header {
+        let header = kept.iter().map(|c| characters(&header[*c])).collect();
+        [data, header].into_iter().collect()
+    } else {
+        data
+    }
+}
+
+fn write(data: &Array, destination: &Array, options: &Options) -> Array {
+    let (data, header) = if options.header {
+        match data.elements().as_slice() {
+            [data, header] => (
+                data.disclosed().clone(),
+                Some(header.elements().iter().map(text).collect()),
+            ),
+            _ => panic!("LENGTH ERROR"),
+        }
+    } else {
+        (data.clone(), None)
+    };
+
+    let rows: Vec<Vec<String>> = if options.columns {
+        let columns: Vec<Vec<String>> = data
+            .elements()
+            .iter()
+            .map(|c| c.disclosed().elements().iter().map(field).collect())
+            .collect();
+
+        let length = columns.first().map_or(0, Vec::len);
+        if columns.iter().any(|c| c.len() != length) {
+            panic!("LENGTH ERROR");
+        }
+
+        (0..length)
+            .map(|r| columns.iter().map(|c| c[r].clone()).collect())
+            .collect()
+    } else {
+        if data.rank() != 2 {
+            panic!("RANK ERROR");
+        }
+
+        data.major_cells()
+            .iter()
+            .map(|row| row.elements().iter().map(field).collect())
+            .collect()
+    };
+
+    let mut writer = WriterBuilder::new();
+    writer.flexible(true).delimiter(options.separator);
+    match options.quote {
+        Some(quote) => writer.quote(quote).quote_style(QuoteStyle::Necessary),
+        None => writer.quote_style(QuoteStyle::Never),
+    };
+
+    let mut writer = writer.from_writer(vec![]);
+    for record in header.into_iter().chain(rows) {
+        writer.write_record(&record).unwrap();
+    }
+    let content = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+    match options.description.as_str() {
+        "" => {
+            fs::write(text(destination), &content).unwrap_or_else(fail);
+            Array::Scalar(Scalar::Integer(content.len() as i64))
+        }
+        "S" => characters(&content),
+        "L" => content
+            .lines()
+            .map(characters)
+            .collect::<Array>()
+            .with_prototype(characters("")),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// The number a field holds, if it holds one.
+fn number(field: &str) -> Option<Scalar> {
+    let field = field.trim();
+    let field = field.strip_prefix('+').unwrap_or(field);
+    let unsigned = field.strip_prefix('-').unwrap_or(field);
+
+    // Rust would also read words such as `inf` and `NaN` as floats.
+    let numeric = unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && unsigned
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '-' | '+'))
+        && field.parse::<f64>().is_ok_and(f64::is_finite);
+
+    numeric.then(|| Scalar::from_literal(field))
+}
+
+/// Writes a cell of the data as a field, with numbers in full whatever `⎕PP` is.
+fn field(cell: &Array) -> String {
+    match cell.disclosed() {
+        a if is_text(a) => text(a),
+        Array::Scalar(s) => real(s),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+fn integer(a: &Array) -> i64 {
+    match a {
+        Array::Scalar(s) => s.as_integer(),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// A separator or quote, which must be a single ASCII character.
+fn byte(a: &Array) -> u8 {
+    match text(a).as_bytes() {
+        [b] => *b,
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
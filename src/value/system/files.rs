@@ -0,0 +1,305 @@
+use std::{
+    fs::{self, Metadata, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use chrono::{DateTime, Local};
+
+use super::{characters, integers, text, timestamp};
+use crate::value::array::{scalar::Scalar, Array};
+
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// `⎕NGET`: reads a text file, given its name and optionally a flag of 1 to split it into lines.
+/// Gives the content, the name of its encoding and the code points of its line ending.
+pub fn get(omega: &Array) -> Array {
+    let (name, flag) = name_and_flag(omega);
+    let bytes = fs::read(name).unwrap_or_else(fail);
+
+    let (bytes, marked) = match bytes.strip_prefix(BYTE_ORDER_MARK) {
+        Some(rest) => (rest, true),
+        None => (&bytes[..], false),
+    };
+
+    let (content, encoding) = match std::str::from_utf8(bytes) {
+        Ok(s) if marked => (s.to_string(), "UTF-8-BOM"),
+        Ok(s) => (s.to_string(), "UTF-8"),
+        // Text that is not UTF-8 is taken to have one character to a byte.
+        Err(_) => (bytes.iter().map(|b| *b as char).collect(), "ISO-8859-1"),
+    };
+
+    // The first line ending decides the convention for the whole file.
+    let newline = match content.find(['\r', '\n']) {
+        Some(i) if content[i..].starts_with("\r\n") => "\r\n",
+        Some(i) if content[i..].starts_with('\r') => "\r",
+        _ => "\n",
+    };
+
+    let content = match flag {
+        0 => characters(&content),
+        1 => {
+            let mut lines: Vec<&str> = content.split(newline).collect();
+            if lines.last() == Some(&"") {
+                lines.pop();
+            }
+
+            lines
+                .into_iter()
+                .map(characters)
+                .collect::<Array>()
+                .with_prototype(characters(""))
+        }
+        _ => panic!("DOMAIN ERROR"),
+    };
+
+    [
+        content,
+        characters(encoding),
+        integers(newline.chars().map(|c| c as i64)),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// `⎕NPUT`: writes text to a file, given the content alone or followed by an encoding and the
+/// code points of a line ending, and the name of the file optionally followed by a flag. The flag
+/// is 0 to only write a new file, 1 to overwrite an existing one, or 2 to append to it. The
+/// content may also be a vector of lines, which must then be followed by its encoding, or a
+/// matrix whose rows are the lines, and each line has the line ending put after it. Gives the
+/// number of bytes written.
+pub fn put(alpha: &Array, omega: &Array) -> Array {
+    let (name, flag) = name_and_flag(omega);
+
+    let items = if alpha.depth().abs() <= 1 {
+        vec![alpha.clone()]
+    } else {
+        alpha.elements()
+    };
+
+    if items.is_empty() || items.len() > 3 {
+        panic!("LENGTH ERROR");
+    }
+
+    let encoding = items.get(1).map_or("UTF-8".to_string(), text);
+    let newline: String = match items.get(2) {
+        Some(codes) => codes
+            .elements()
+            .iter()
+            .map(|c| match c {
+                Array::Scalar(s) => u32::try_from(s.as_integer())
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or_else(|| panic!("DOMAIN ERROR")),
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect(),
+        None => "\n".to_string(),
+    };
+
+    let content = items[0].disclosed();
+    let content = if content.rank() == 2 {
+        content
+            .major_cells()
+            .iter()
+            .map(|row| text(row) + &newline)
+            .collect()
+    } else if content.depth().abs() > 1 {
+        content
+            .elements()
+            .iter()
+            .map(|line| text(line) + &newline)
+            .collect()
+    } else {
+        text(content).replace('\n', &newline)
+    };
+
+    let mut bytes = match encoding.as_str() {
+        "UTF-8" | "UTF-8-BOM" => content.into_bytes(),
+        "ISO-8859-1" => content
+            .chars()
+            .map(|c| u8::try_from(c).unwrap_or_else(|_| panic!("DOMAIN ERROR")))
+            .collect(),
+        _ => panic!("DOMAIN ERROR"),
+    };
+
+    let mut options = OpenOptions::new();
+    match flag {
+        0 => options.write(true).create_new(true),
+        1 => options.write(true).create(true).truncate(true),
+        2 => options.append(true).create(true),
+        _ => panic!("DOMAIN ERROR"),
+    };
+
+    let mut file = options.open(name).unwrap_or_else(fail);
+
+    // Text appended to a file that already has some must not start with another mark.
+    if encoding == "UTF-8-BOM" && file.metadata().unwrap_or_else(fail).len() == 0 {
+        bytes.splice(0..0, BYTE_ORDER_MARK.iter().copied());
+    }
+
+    file.write_all(&bytes).unwrap_or_else(fail);
+    Array::Scalar(Scalar::Integer(bytes.len() as i64))
+}
+
+/// `⎕NEXISTS`: whether there is a file or directory with the given name.
+pub fn exists(omega: &Array) -> Array {
+    Array::Scalar(Scalar::Integer(Path::new(&text(omega)).exists() as i64))
+}
+
+/// `⎕NDELETE`: deletes a file or an empty directory, giving 1 if it was there and 0 if not.
+pub fn delete(omega: &Array) -> Array {
+    let name = text(omega);
+    let path = Path::new(&name);
+
+    let deleted = match fs::symlink_metadata(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+        Ok(metadata) if metadata.is_dir() => fs::remove_dir(path).map(|_| true),
+        Ok(_) => fs::remove_file(path).map(|_| true),
+    }
+    .unwrap_or_else(fail);
+
+    Array::Scalar(Scalar::Integer(deleted as i64))
+}
+
+/// `⎕NINFO`: the given properties of a file, which are 0 for its name, 1 for its type, 2 for its
+/// size in bytes and 3 for the time it was last modified, defaulting to its name. The type is 1
+/// for a directory, 2 for a regular file, 4 for a symbolic link and 0 for anything else.
+///
+/// With a flag of 1 after the name, the last part of the name may have the wildcards `*` and `?`,
+/// and each property is given as a vector over the matching files in order.
+pub fn info(alpha: Option<&Array>, omega: &Array) -> Array {
+    let (name, flag) = name_and_flag(omega);
+
+    let properties = match alpha {
+        Some(a) if a.rank() > 1 => panic!("RANK ERROR"),
+        Some(a) => a
+            .elements()
+            .iter()
+            .map(|p| match p {
+                Array::Scalar(s) => s.as_integer(),
+                _ => panic!("DOMAIN ERROR"),
+            })
+            .collect(),
+        None => vec![0],
+    };
+
+    let files: Vec<(String, Metadata)> = match flag {
+        0 => vec![name],
+        1 => matching(&name),
+        _ => panic!("DOMAIN ERROR"),
+    }
+    .into_iter()
+    .map(|name| {
+        let metadata = fs::symlink_metadata(&name).unwrap_or_else(fail);
+        (name, metadata)
+    })
+    .collect();
+
+    let property = |property: &i64| {
+        let mut values = files
+            .iter()
+            .map(|(name, metadata)| file_property(*property, name, metadata));
+
+        if flag == 1 {
+            let prototype = match property {
+                0 => characters(""),
+                3 => integers([0; 7]),
+                _ => Array::Scalar(Scalar::Integer(0)),
+            };
+            values.collect::<Array>().with_prototype(prototype)
+        } else {
+            values.next().unwrap()
+        }
+    };
+
+    match alpha {
+        Some(a) if a.rank() == 1 => properties.iter().map(property).collect(),
+        _ => property(&properties[0]),
+    }
+}
+
+fn file_property(property: i64, name: &str, metadata: &Metadata) -> Array {
+    match property {
+        0 => characters(name),
+        1 => {
+            let kind = metadata.file_type();
+            Array::Scalar(Scalar::Integer(if kind.is_dir() {
+                1
+            } else if kind.is_file() {
+                2
+            } else if kind.is_symlink() {
+                4
+            } else {
+                0
+            }))
+        }
+        2 => Array::Scalar(Scalar::Integer(metadata.len() as i64)),
+        3 => timestamp(DateTime::<Local>::from(
+            metadata.modified().unwrap_or_else(fail),
+        )),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// The names of the files in a directory whose names match the wildcards in the last part of the
+/// pattern, sorted.
+fn matching(pattern: &str) -> Vec<String> {
+    let path = Path::new(pattern);
+    let wildcards: Vec<char> = match path.file_name() {
+        Some(name) => name.to_string_lossy().chars().collect(),
+        None => panic!("DOMAIN ERROR"),
+    };
+
+    let parent = path.parent().unwrap_or(Path::new(""));
+    let directory = if parent.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        parent
+    };
+
+    let mut names: Vec<String> = fs::read_dir(directory)
+        .unwrap_or_else(fail)
+        .map(|entry| entry.unwrap_or_else(fail).file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| fits_wildcards(&wildcards, &name.chars().collect::<Vec<_>>()))
+        .map(|name| parent.join(name).to_string_lossy().into_owned())
+        .collect();
+
+    names.sort();
+    names
+}
+
+/// Whether the name matches the pattern, where `*` stands for any run of characters and `?` for
+/// any one character.
+fn fits_wildcards(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| fits_wildcards(rest, &name[i..])),
+        Some(('?', rest)) => !name.is_empty() && fits_wildcards(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && fits_wildcards(rest, &name[1..]),
+    }
+}
+
+/// Splits the argument of a file function into the name of the file and the flag after it, which
+/// is 0 if there is none.
+fn name_and_flag(omega: &Array) -> (String, i64) {
+    if omega.depth().abs() <= 1 {
+        return (text(omega), 0);
+    }
+
+    match omega.elements().as_slice() {
+        [name] => (text(name), 0),
+        [name, Array::Scalar(flag)] => (text(name), flag.as_integer()),
+        _ => panic!("LENGTH ERROR"),
+    }
+}
+
+/// Reports a file operation that failed as an APL error.
+pub fn fail<T>(error: io::Error) -> T {
+    match error.kind() {
+        io::ErrorKind::NotFound | io::ErrorKind::AlreadyExists => panic!("FILE NAME ERROR"),
+        _ => panic!("FILE ACCESS ERROR"),
+    }
+}
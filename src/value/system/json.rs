@@ -0,0 +1,234 @@
+use serde_json::{Map, Number, Value};
+
+use super::{characters, is_text, real, text};
+use crate::value::array::{scalar::Scalar, Array};
+
+/// `⎕JSON`: converts JSON text to an array, or an array to JSON text. The left argument is 0 to
+/// import or 1 to export, followed by any of the option letters `M` for the matrix format, `C`
+/// for compact output and `P` for pretty output. Without one, character arguments are imported
+/// and anything else is exported.
+///
+/// Objects are matrices with a row for each member, holding its name and then its value, and
+/// arrays are vectors. Any two-column matrix whose first column holds names is therefore exported
+/// as an object, so pairs meant as an array must be a vector of vectors. In the matrix format, the whole value is instead a matrix with a row for
+/// each object, array and item, holding its depth, its name within an object, its value if it has
+/// no items, and its type, which is 1 for an object, 2 for an array, 3 for a number, 4 for a
+/// string and 5 for a literal. The literals `true`, `false` and `null` are their names enclosed
+/// twice, so that they do not match strings holding the same names.
+pub fn json(alpha: Option<&Array>, omega: &Array) -> Array {
+    let (options, letters) = match alpha {
+        Some(a) if a.rank() > 1 => panic!("RANK ERROR"),
+        Some(a) => a.elements().split_first().map_or_else(
+            || panic!("LENGTH ERROR"),
+            |(direction, letters)| (Some(direction.clone()), letters.to_vec()),
+        ),
+        None => (None, vec![]),
+    };
+
+    let export = match options {
+        Some(Array::Scalar(Scalar::Character(_))) | Some(Array::Scalar(Scalar::Array(_))) => {
+            panic!("DOMAIN ERROR")
+        }
+        Some(Array::Scalar(direction)) => match direction.as_integer() {
+            0 => false,
+            1 => true,
+            _ => panic!("DOMAIN ERROR"),
+        },
+        Some(_) => panic!("DOMAIN ERROR"),
+        None => !is_text(omega),
+    };
+
+    let (mut matrix, mut pretty) = (false, false);
+    for letter in letters {
+        match letter {
+            Array::Scalar(Scalar::Character('M')) => matrix = true,
+            Array::Scalar(Scalar::Character('C')) => pretty = false,
+            Array::Scalar(Scalar::Character('P')) => pretty = true,
+            _ => panic!("DOMAIN ERROR"),
+        }
+    }
+
+    if export {
+        let value = if matrix {
+            from_matrix(omega)
+        } else {
+            to_json(omega)
+        };
+
+        let json = if pretty {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        };
+        characters(&json.unwrap())
+    } else {
+        let value: Value = serde_json::from_str(&text(omega))
+            .unwrap_or_else(|e| panic!("DOMAIN ERROR: invalid JSON: {}", e));
+
+        if matrix {
+            let mut rows = vec![];
+            to_rows(&value, 0, "", &mut rows);
+            let count = rows.len();
+            Array::from_shape(vec![count, 4], rows.concat())
+        } else {
+            from_json(&value)
+        }
+    }
+}
+
+fn from_json(value: &Value) -> Array {
+    match value {
+        Value::Object(members) => {
+            let elements = members
+                .iter()
+                .flat_map(|(name, value)| [characters(name), from_json(value)])
+                .collect();
+            Array::from_shape(vec![members.len(), 2], elements)
+        }
+        Value::Array(items) => items.iter().map(from_json).collect(),
+        Value::String(s) => characters(s),
+        Value::Number(n) => Array::Scalar(Scalar::from_literal(&n.to_string()).finite()),
+        Value::Bool(b) => literal(&b.to_string()),
+        Value::Null => literal("null"),
+    }
+}
+
+fn literal(name: &str) -> Array {
+    let enclose = |a| Array::Scalar(Scalar::Array(Box::new(a)));
+    enclose(enclose(characters(name)))
+}
+
+/// Appends a row to the matrix format for the value and then rows for each of its items.
+fn to_rows(value: &Value, depth: i64, name: &str, rows: &mut Vec<Vec<Array>>) {
+    let (contents, kind) = match value {
+        Value::Object(_) => (Array::Empty(vec![0], Box::new(zero())), 1),
+        Value::Array(_) => (Array::Empty(vec![0], Box::new(zero())), 2),
+        Value::Number(_) => (from_json(value), 3),
+        Value::String(_) => (from_json(value), 4),
+        Value::Bool(_) | Value::Null => (from_json(value), 5),
+    };
+
+    rows.push(vec![
+        Array::Scalar(Scalar::Integer(depth)),
+        characters(name),
+        contents,
+        Array::Scalar(Scalar::Integer(kind)),
+    ]);
+
+    match value {
+        Value::Object(members) => {
+            for (name, value) in members {
+                to_rows(value, depth + 1, name, rows);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                to_rows(item, depth + 1, "", rows);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn to_json(array: &Array) -> Value {
+    match array {
+        Array::Scalar(Scalar::Array(a)) => {
+            let name = match &**a {
+                Array::Scalar(Scalar::Array(name)) if is_text(name) => Some(text(name)),
+                _ => None,
+            };
+            match name.as_deref() {
+                Some("true") => Value::Bool(true),
+                Some("false") => Value::Bool(false),
+                Some("null") => Value::Null,
+                _ => to_json(a),
+            }
+        }
+        Array::Scalar(Scalar::Character(c)) => Value::String(c.to_string()),
+        Array::Scalar(s) => Value::Number(number(s)),
+        a if is_text(a) => Value::String(text(a)),
+        a if a.rank() == 1 => Value::Array(a.elements().iter().map(to_json).collect()),
+        a if a.rank() == 2 && a.shape()[1] == 2 => {
+            let elements = a.elements();
+            let members = elements.chunks(2).map(|member| {
+                // Names must be vectors so that a simple character matrix is not taken for one.
+                match member[0].disclosed() {
+                    name if name.rank() == 1 && is_text(name) => (text(name), to_json(&member[1])),
+                    _ => panic!("DOMAIN ERROR"),
+                }
+            });
+            Value::Object(members.collect())
+        }
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+fn number(s: &Scalar) -> Number {
+    real(s).parse().unwrap()
+}
+
+/// Reads an array back from the matrix format.
+fn from_matrix(array: &Array) -> Value {
+    if array.rank() != 2 || array.shape()[1] != 4 {
+        panic!("RANK ERROR");
+    }
+
+    if array.shape()[0] == 0 {
+        panic!("LENGTH ERROR");
+    }
+
+    let rows = array.major_cells();
+    let mut next = 0;
+    let (_, value) = from_rows(&rows, &mut next, None);
+
+    if next != rows.len() {
+        panic!("DOMAIN ERROR");
+    }
+    value
+}
+
+/// Reads the value in the next row, and the values in the rows after it one level deeper, giving
+/// its name too.
+fn from_rows(rows: &[Array], next: &mut usize, parent: Option<i64>) -> (String, Value) {
+    let row = rows[*next].elements();
+    *next += 1;
+
+    let integer = |a: &Array| match a {
+        Array::Scalar(s) => s.as_integer(),
+        _ => panic!("DOMAIN ERROR"),
+    };
+    let depth = integer(&row[0]);
+    let name = text(&row[1]);
+
+    if parent.is_some_and(|p| depth != p + 1) {
+        panic!("DOMAIN ERROR");
+    }
+
+    let mut items = vec![];
+    if matches!(integer(&row[3]), 1 | 2) {
+        while *next < rows.len() && integer(&rows[*next].elements()[0]) > depth {
+            items.push(from_rows(rows, next, Some(depth)));
+        }
+    }
+
+    let value = match integer(&row[3]) {
+        1 => Value::Object(items.into_iter().collect::<Map<_, _>>()),
+        2 => Value::Array(items.into_iter().map(|(_, v)| v).collect()),
+        3 => match &row[2] {
+            Array::Scalar(s) => Value::Number(number(s)),
+            _ => panic!("DOMAIN ERROR"),
+        },
+        4 => Value::String(text(&row[2])),
+        5 => match to_json(&row[2]) {
+            value @ (Value::Bool(_) | Value::Null) => value,
+            _ => panic!("DOMAIN ERROR"),
+        },
+        _ => panic!("DOMAIN ERROR"),
+    };
+
+    (name, value)
+}
+
+fn zero() -> Array {
+    Array::Scalar(Scalar::Integer(0))
+}
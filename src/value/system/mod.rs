@@ -0,0 +1,334 @@
+use std::{
+    cell::{Cell, RefCell},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+
+use super::array::{scalar::Scalar, Array};
+
+mod csv;
+mod files;
+mod json;
+
+/// The values of the system variables that change how primitives behave. They are global to the
+/// session: there are no dfns yet for them to be localised in.
+#[derive(Clone)]
+struct Variables {
+    /// `⎕IO`: the index of the first item along an axis, 0 or 1.
+    index_origin: i64,
+    /// `⎕CT`: the relative difference below which two numbers that are not both integers compare
+    /// equal.
+    comparison_tolerance: f64,
+    /// `⎕PP`: the number of significant digits used to display numbers that are not integers.
+    print_precision: usize,
+    /// `⎕ML`: the migration level, which at 2 or above swaps monadic `↑` and `⊃`.
+    migration_level: i64,
+    /// `⎕RL`: the state of the random number generator.
+    random_link: i64,
+    /// `⎕FR`: 645 for binary doubles or 1287 for 128-bit decimals.
+    float_representation: i64,
+}
+
+const DEFAULTS: Variables = Variables {
+    index_origin: 1,
+    comparison_tolerance: 1e-14,
+    print_precision: 10,
+    migration_level: 1,
+    random_link: 16807,
+    float_representation: 645,
+};
+
+/// The modulus of the Lehmer generator behind `?`, which is the Mersenne prime `2*31-1`.
+const RANDOM_MODULUS: i64 = 2147483647;
+
+thread_local! {
+    static VARIABLES: RefCell<Variables> = const { RefCell::new(DEFAULTS) };
+    static BIG_INTEGERS: Cell<bool> = const { Cell::new(false) };
+    static RATIONALS: Cell<bool> = const { Cell::new(false) };
+    static CONNECTED: Instant = Instant::now();
+}
+
+/// Starts the clock that `⎕AI` gives the connect time from, which should be done when the session
+/// begins.
+pub fn connect() {
+    CONNECTED.with(|_| ());
+}
+
+fn read<T>(field: impl FnOnce(&Variables) -> T) -> T {
+    VARIABLES.with_borrow(field)
+}
+
+/// Whether integers that overflow 64 bits are kept exact rather than promoted to floats.
+pub fn big_integers() -> bool {
+    BIG_INTEGERS.get()
+}
+
+pub fn set_big_integers(on: bool) {
+    BIG_INTEGERS.set(on);
+}
+
+/// Whether dividing integers that do not go into each other gives an exact ratio rather than a
+/// float.
+pub fn rationals() -> bool {
+    RATIONALS.get()
+}
+
+pub fn set_rationals(on: bool) {
+    RATIONALS.set(on);
+}
+
+pub fn index_origin() -> i64 {
+    read(|v| v.index_origin)
+}
+
+/// Whether numbers that are not integers are held as 128-bit decimals (`⎕FR←1287`) rather than
+/// binary doubles (`⎕FR←645`).
+pub fn decimal_floats() -> bool {
+    read(|v| v.float_representation == 1287)
+}
+
+/// The relative difference below which two numbers that are not both integers compare equal.
+pub fn comparison_tolerance() -> f64 {
+    read(|v| v.comparison_tolerance)
+}
+
+/// The number of significant digits used to display floating-point numbers.
+pub fn print_precision() -> usize {
+    read(|v| v.print_precision)
+}
+
+pub fn migration_level() -> i64 {
+    read(|v| v.migration_level)
+}
+
+/// Advances the random link and gives a number drawn uniformly from between 0 and 1.
+pub fn random() -> f64 {
+    VARIABLES.with_borrow_mut(|v| {
+        v.random_link = v.random_link * 16807 % RANDOM_MODULUS;
+        v.random_link as f64 / RANDOM_MODULUS as f64
+    })
+}
+
+/// Whether the name, without its quad, is that of a system function rather than a variable.
+pub fn is_function(name: &str) -> bool {
+    matches!(
+        name,
+        "CSV" | "DL" | "GETENV" | "JSON" | "NGET" | "NPUT" | "NEXISTS" | "NINFO" | "NDELETE"
+    )
+}
+
+/// Applies the system function with the given name, without its quad.
+pub fn call(name: &str, alpha: Option<Array>, omega: Array) -> Array {
+    match name {
+        "NPUT" => return files::put(&alpha.unwrap_or_else(|| panic!("VALENCE ERROR")), &omega),
+        "NINFO" => return files::info(alpha.as_ref(), &omega),
+        "JSON" => return json::json(alpha.as_ref(), &omega),
+        "CSV" => return csv::csv(alpha.as_ref(), &omega),
+        _ if alpha.is_some() => panic!("VALENCE ERROR"),
+        _ => (),
+    }
+
+    match name {
+        // `⎕DL`: waits for the given number of seconds and gives the time actually waited.
+        "DL" => {
+            let seconds = match omega {
+                Array::Scalar(s) => s.as_float(),
+                _ => panic!("RANK ERROR"),
+            };
+            let delay = Duration::try_from_secs_f64(seconds.max(0.0))
+                .unwrap_or_else(|_| panic!("DOMAIN ERROR"));
+
+            let start = Instant::now();
+            thread::sleep(delay);
+            Array::Scalar(Scalar::Float(start.elapsed().as_secs_f64()))
+        }
+        // `⎕GETENV`: the value of an environment variable, or an empty vector if it is not set.
+        "GETENV" => characters(&std::env::var(text(&omega)).unwrap_or_default()),
+        "NGET" => files::get(&omega),
+        "NEXISTS" => files::exists(&omega),
+        "NDELETE" => files::delete(&omega),
+        _ => unreachable!(),
+    }
+}
+
+/// The value of the system variable with the given name, without its quad. The niladic system
+/// functions are read here too.
+pub fn get(name: &str) -> Array {
+    let v = read(Variables::clone);
+
+    Array::Scalar(match name {
+        // `⎕TS`: the local time as year, month, day, hour, minute, second and millisecond.
+        "TS" => {
+            return timestamp(Local::now());
+        }
+        // `⎕AI`: the user id, and the processor, connect and keying times in milliseconds. Time
+        // spent waiting for input is not tracked, so the keying time is always 0.
+        "AI" => {
+            let connected = CONNECTED.with(Instant::elapsed).as_millis() as i64;
+            return integers([user_id(), processor_time(), connected, 0]);
+        }
+        // `⎕NOW`: the time in seconds since the start of 1970 in UTC.
+        "NOW" => Scalar::Float(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs_f64(),
+        ),
+        "IO" => Scalar::Integer(v.index_origin),
+        "CT" => Scalar::Float(v.comparison_tolerance),
+        "PP" => Scalar::Integer(v.print_precision as i64),
+        "ML" => Scalar::Integer(v.migration_level),
+        "RL" => Scalar::Integer(v.random_link),
+        "FR" => Scalar::Integer(v.float_representation),
+        _ => panic!("VALUE ERROR"),
+    })
+}
+
+/// A time as year, month, day, hour, minute, second and millisecond, as `⎕TS` gives it.
+fn timestamp(time: DateTime<Local>) -> Array {
+    integers([
+        time.year() as i64,
+        time.month() as i64,
+        time.day() as i64,
+        time.hour() as i64,
+        time.minute() as i64,
+        time.second() as i64,
+        time.timestamp_subsec_millis() as i64,
+    ])
+}
+
+/// Assigns to the system variable with the given name, checking that the value is valid for it.
+pub fn set(name: &str, value: &Array) {
+    let value = match value {
+        Array::Scalar(s) => s,
+        _ => panic!("RANK ERROR"),
+    };
+
+    let integer_in = |range: std::ops::RangeInclusive<i64>| {
+        let n = value.as_integer();
+        if !range.contains(&n) {
+            panic!("DOMAIN ERROR");
+        }
+        n
+    };
+
+    VARIABLES.with_borrow_mut(|v| match name {
+        "IO" => v.index_origin = integer_in(0..=1),
+        "CT" => {
+            let tolerance = value.as_float();
+
+            // Any larger and integers a unit apart could compare equal.
+            if !(0.0..=2f64.powi(-32)).contains(&tolerance) {
+                panic!("DOMAIN ERROR");
+            }
+
+            v.comparison_tolerance = tolerance;
+        }
+        // Decimals are shown to no more than the 34 significant digits of decimal128, and doubles
+        // to no more than 17.
+        "PP" => v.print_precision = integer_in(1..=i64::MAX).min(34) as usize,
+        "ML" => v.migration_level = integer_in(0..=3),
+        "RL" => v.random_link = integer_in(1..=RANDOM_MODULUS - 1),
+        "FR" => {
+            v.float_representation = match value.as_integer() {
+                representation @ (645 | 1287) => representation,
+                _ => panic!("DOMAIN ERROR"),
+            }
+        }
+        _ => panic!("SYNTAX ERROR"),
+    });
+}
+
+/// Whether the array is a character scalar or vector, which may be empty.
+fn is_text(array: &Array) -> bool {
+    match array {
+        Array::Scalar(Scalar::Character(_)) => true,
+        Array::Vector(items) => items
+            .iter()
+            .all(|c| matches!(c, Array::Scalar(Scalar::Character(_)))),
+        Array::Empty(shape, prototype) => {
+            shape.len() == 1 && matches!(**prototype, Array::Scalar(Scalar::Character(_)))
+        }
+        _ => false,
+    }
+}
+
+/// The text held in a character scalar or vector.
+fn text(array: &Array) -> String {
+    if array.disclosed().rank() > 1 {
+        panic!("RANK ERROR");
+    }
+
+    array
+        .disclosed()
+        .ravel()
+        .elements()
+        .iter()
+        .map(|c| match c {
+            Array::Scalar(Scalar::Character(c)) => *c,
+            _ => panic!("DOMAIN ERROR"),
+        })
+        .collect()
+}
+
+/// A character vector holding the text, which is empty with a blank prototype if there is none.
+fn characters(text: &str) -> Array {
+    text.chars()
+        .map(|c| Array::Scalar(Scalar::Character(c)))
+        .collect::<Array>()
+        .with_prototype(Array::Scalar(Scalar::Character(' ')))
+}
+
+/// A real number written in full in Rust's notation, for exporting it as text. Complex numbers
+/// and anything else that is not a real number are a DOMAIN ERROR.
+fn real(s: &Scalar) -> String {
+    match s {
+        Scalar::Integer(i) => i.to_string(),
+        Scalar::BigInteger(b) => b.to_string(),
+        Scalar::Decimal(d) => d.reduce().to_string(),
+        Scalar::Rational(_) | Scalar::Float(_) => format!("{:?}", s.as_float()),
+        _ => panic!("DOMAIN ERROR"),
+    }
+}
+
+/// A simple vector of integers.
+fn integers(values: impl IntoIterator<Item = i64>) -> Array {
+    values
+        .into_iter()
+        .map(|i| Array::Scalar(Scalar::Integer(i)))
+        .collect()
+}
+
+#[cfg(unix)]
+fn user_id() -> i64 {
+    // SAFETY: `getuid` has no preconditions and cannot fail.
+    unsafe { libc::getuid() as i64 }
+}
+
+#[cfg(not(unix))]
+fn user_id() -> i64 {
+    0
+}
+
+/// The processor time used by the interpreter so far, in milliseconds.
+#[cfg(unix)]
+fn processor_time() -> i64 {
+    // SAFETY: `getrusage` only writes to the zeroed struct it is given.
+    let usage = unsafe {
+        let mut usage: libc::rusage = std::mem::zeroed();
+        libc::getrusage(libc::RUSAGE_SELF, &mut usage);
+        usage
+    };
+
+    // The fields are narrower than 64 bits on some targets.
+    #[allow(clippy::unnecessary_cast)]
+    let millis = |t: libc::timeval| t.tv_sec as i64 * 1000 + t.tv_usec as i64 / 1000;
+    millis(usage.ru_utime) + millis(usage.ru_stime)
+}
+
+#[cfg(not(unix))]
+fn processor_time() -> i64 {
+    0
+}